@@ -0,0 +1,51 @@
+#!/usr/bin/env bash
+# Times the grid-heavy days with release builds: 2022 day08 (parts a and b) and 2021 day15.
+#
+#   ./bench.sh                  time the working tree
+#   ./bench.sh c59a393~1        also time a git revision, e.g. the one before a change, for comparison
+#
+# Each day runs RUNS times (default 10), the best and median wall-clock times in ms are reported.
+# Day08 takes well under a millisecond, less than starting the process, so every run solves it REPEAT times
+# (default 100) in a single process and reports the time per solve.
+# The revision is checked out in a temporary worktree, so its first run includes a full build.
+set -euo pipefail
+
+RUNS="${RUNS:-10}"
+REPEAT="${REPEAT:-100}"
+ROOT="$(cd "$(dirname "$0")" && pwd)"
+
+# Run a command RUNS times from the current directory, printing "best median" in ms divided by the first argument.
+time_runs() {
+  local per="$1" times=()
+  shift
+  for _ in $(seq "$RUNS"); do
+    local start end
+    start=$(date +%s%N)
+    "$@" > /dev/null
+    end=$(date +%s%N)
+    times+=($(( (end - start) / 1000 / per )))
+  done
+  printf '%s\n' "${times[@]}" | sort -n | awk '
+    { t[NR] = $1 }
+    END { printf "best %8.2fms  median %8.2fms\n", t[1] / 1000, t[int((NR + 1) / 2)] / 1000 }'
+}
+
+bench_tree() {
+  local tree="$1" label="$2"
+  echo "== $label"
+
+  (cd "$tree/year2022/rust" && cargo build --quiet --release 2> /dev/null &&
+    printf '  2022 day08  ' && time_runs "$REPEAT" ./target/release/aoc2022 $(printf '8a 8b %.0s' $(seq "$REPEAT")))
+
+  (cd "$tree/year2021/rust" && cargo build --quiet --release --bin day15 2> /dev/null &&
+    printf '  2021 day15  ' && time_runs 1 ./target/release/day15)
+}
+
+bench_tree "$ROOT" "working tree"
+
+if [[ $# -gt 0 ]]; then
+  worktree="$(mktemp -d)"
+  trap 'git -C "$ROOT" worktree remove --force "$worktree"' EXIT
+  git -C "$ROOT" worktree add --quiet --detach "$worktree" "$1"
+  bench_tree "$worktree" "$1"
+fi
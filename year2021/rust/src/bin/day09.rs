@@ -1,8 +1,8 @@
-use std::collections::{HashSet, VecDeque};
 use std::fs;
 use std::time::Instant;
 
-use itertools::Itertools;
+use year2021::grid::{Grid, Neighborhood, Position};
+use year2021::grid_algorithms::components;
 
 fn main() {
     let start = Instant::now();
@@ -15,15 +15,7 @@ fn part1() -> Option<u64> {
     let start = Instant::now();
     let input = read_input()?;
 
-    let rows = input.len();
-    let columns = input[0].len();
-
-    let mut result = 0;
-    for c in 0..columns {
-        for r in 0..rows {
-            result += risk_level(&input, r, c);
-        }
-    }
+    let result = input.iter_positions().map(|p| risk_level(&input, &p)).sum();
 
     println!("[part1] time={:?}", start.elapsed());
     return Some(result);
@@ -33,19 +25,12 @@ fn part2() -> Option<u64> {
     let start = Instant::now();
     let input = read_input()?;
 
-    let rows = input.len();
-    let columns = input[0].len();
-
-    let mut sizes = vec![];
-    for c in 0..columns {
-        for r in 0..rows {
-            if risk_level(&input, r, c) > 0 {
-                let size = basin_size(&input, r, c);
-                // println!("r={} c={} size={}", r, c, size);
-                sizes.push(size);
-            }
-        }
-    }
+    // basins are bounded by locations of height 9, which are not part of any basin.
+    let basins = components(&input, Neighborhood::Orthogonal, |a, b| *a < 9 && *b < 9);
+    let mut sizes = (0..basins.len())
+        .filter(|label| input[&basins.starts[*label]] < 9)
+        .map(|label| basins.area(label) as u64)
+        .collect::<Vec<_>>();
 
     sizes.sort();
     sizes.reverse();
@@ -56,72 +41,21 @@ fn part2() -> Option<u64> {
     return Some(result);
 }
 
-fn read_input() -> Option<Vec<Vec<u8>>> {
+fn read_input() -> Option<Grid<u8>> {
     let filename = "res/input09.txt";
     let contents = fs::read_to_string(filename).ok()?;
 
-    let input = contents
-        .lines()
-        .map(|line| {
-            line.chars()
-                .filter_map(|c| c.to_string().parse::<u8>().ok())
-                .collect_vec()
-        })
-        .collect_vec();
-
-    return Some(input);
+    return Grid::parse(&contents, |c| c.to_digit(10).unwrap() as u8).ok();
 }
 
-fn risk_level(grid: &Vec<Vec<u8>>, r: usize, c: usize) -> u64 {
-    let rows = grid.len();
-    let columns = grid[0].len();
-    let value = grid[r][c];
-
-    if r > 0 && grid[r - 1][c] <= value {
-        return 0;
-    }
-    if r < rows - 1 && grid[r + 1][c] <= value {
-        return 0;
-    }
-
-    if c > 0 && grid[r][c - 1] <= value {
-        return 0;
-    }
-    if c < columns - 1 && grid[r][c + 1] <= value {
+fn risk_level(grid: &Grid<u8>, position: &Position) -> u64 {
+    let value = grid[position];
+    if position
+        .neighbours(grid.num_rows, grid.num_cols)
+        .any(|neighbour| grid[&neighbour] <= value)
+    {
         return 0;
     }
 
     return 1 + value as u64;
 }
-
-fn basin_size(grid: &Vec<Vec<u8>>, sr: usize, sc: usize) -> u64 {
-    let mut basin: HashSet<(usize, usize)> = HashSet::new();
-    let mut queue: VecDeque<(usize, usize)> = VecDeque::new();
-
-    let rows = grid.len();
-    let columns = grid[0].len();
-
-    queue.push_back((sr, sc));
-
-    while queue.len() > 0 {
-        let (r, c) = queue.pop_front().unwrap();
-        let value = grid[r][c];
-        if value < 9 && basin.insert((r, c)) {
-            if r > 0 && grid[r - 1][c] > value {
-                queue.push_back((r - 1, c));
-            }
-            if r < rows - 1 && grid[r + 1][c] > value {
-                queue.push_back((r + 1, c))
-            }
-
-            if c > 0 && grid[r][c - 1] > value {
-                queue.push_back((r, c - 1));
-            }
-            if c < columns - 1 && grid[r][c + 1] > value {
-                queue.push_back((r, c + 1));
-            }
-        }
-    }
-
-    return basin.len() as u64;
-}
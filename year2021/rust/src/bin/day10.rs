@@ -56,7 +56,7 @@ fn score_corruption(line: &Vec<char>) -> Option<u64> {
     let mut stack: VecDeque<&char> = VecDeque::new();
 
     for c in line {
-        // println!("c={:?} => stack={:?}", c, stack);
+        year2021::aoc_trace!("c={:?} => stack={:?}", c, stack);
         let result = match c {
             '(' | '{' | '[' | '<' => {
                 stack.push_front(c);
@@ -105,7 +105,7 @@ fn score_incomplete(line: &Vec<char>) -> Option<u64> {
 
 fn validate(expected: &char, actual: &char) -> Option<u64> {
     return if expected != actual {
-        // println!("expected={:?} actual={:?}", expected, actual);
+        year2021::aoc_trace!("expected={:?} actual={:?}", expected, actual);
         Some(score_mismatch(actual))
     } else {
         None
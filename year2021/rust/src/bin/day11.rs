@@ -1,7 +1,8 @@
 use std::fs;
 use std::time::Instant;
 
-use itertools::Itertools;
+use year2021::automaton::{run, run_until, Automaton};
+use year2021::grid::{Grid, Neighborhood, Position};
 
 fn main() {
     let start = Instant::now();
@@ -10,28 +11,35 @@ fn main() {
     println!("finished in {:?}", start.elapsed());
 }
 
-fn print(grid: &Vec<Vec<i32>>) {
-    let rows = grid.len();
-    let columns = grid[0].len();
-    for r in 0..rows {
-        for c in 0..columns {
-            print!("{}", grid[r][c]);
-        }
-        println!();
+#[derive(Clone)]
+struct Octopuses {
+    grid: Grid<i32>,
+    flashes: u64,
+}
+
+impl Automaton for Octopuses {
+    fn step(&self) -> Self {
+        let mut grid = self.grid.clone();
+        let flashes = self.flashes + step(&mut grid);
+        return Octopuses { grid, flashes };
     }
+}
+
+fn print(grid: &Grid<i32>) {
+    println!("{}", grid);
     println!();
 }
 
 fn part1() -> Option<u64> {
     let start = Instant::now();
-    let mut input = read_input()?;
+    let input = read_input()?;
     print(&input);
 
-    let mut result = 0;
-    for _ in 0..100 {
-        result += step(&mut input);
-        // print(&input);
-    }
+    let octopuses = Octopuses {
+        grid: input,
+        flashes: 0,
+    };
+    let result = run(octopuses, 100).flashes;
 
     println!("[part1] time={:?}", start.elapsed());
     return Some(result);
@@ -39,77 +47,53 @@ fn part1() -> Option<u64> {
 
 fn part2() -> Option<u64> {
     let start = Instant::now();
-    let mut input = read_input()?;
-
-    let rows = input.len();
-    let columns = input[0].len();
-
-    let mut turn = 0;
-    let mut sync = false;
-    while !sync {
-        step(&mut input);
-        turn += 1;
-        sync = true;
-        for c in 0..columns {
-            for r in 0..rows {
-                if input[c][r] != 0 {
-                    sync = false;
-                }
-            }
-        }
-    }
+    let input = read_input()?;
+
+    let octopuses = Octopuses {
+        grid: input,
+        flashes: 0,
+    };
+    let (_, turn) = run_until(octopuses, |_, next| {
+        next.grid.iter().all(|value| *value == 0)
+    });
 
     println!("[part2] time={:?}", start.elapsed());
-    return Some(turn);
+    return Some(turn as u64);
 }
 
-fn read_input() -> Option<Vec<Vec<i32>>> {
+fn read_input() -> Option<Grid<i32>> {
     let filename = "res/input11.txt";
     let contents = fs::read_to_string(filename).ok()?;
 
-    let input = contents
-        .lines()
-        .map(|line| {
-            line.chars()
-                .filter_map(|c| c.to_string().parse::<i32>().ok())
-                .collect_vec()
-        })
-        .collect_vec();
-
-    return Some(input);
+    return Grid::parse(&contents, |c| c.to_digit(10).unwrap() as i32).ok();
 }
 
-fn step(grid: &mut Vec<Vec<i32>>) -> u64 {
+fn step(grid: &mut Grid<i32>) -> u64 {
     increase(grid);
     return explode(grid);
 }
 
-fn increase(grid: &mut Vec<Vec<i32>>) {
-    let rows = grid.len();
-    let columns = grid[0].len();
-    for c in 0..columns {
-        for r in 0..rows {
-            grid[r][c] += 1;
+fn increase(grid: &mut Grid<i32>) {
+    for c in 0..grid.num_cols {
+        for r in 0..grid.num_rows {
+            grid[&Position::new(r, c)] += 1;
         }
     }
 }
 
-fn explode(grid: &mut Vec<Vec<i32>>) -> u64 {
-    let rows = grid.len();
-    let columns = grid[0].len();
-
+fn explode(grid: &mut Grid<i32>) -> u64 {
     let mut count = 0;
-    for c in 0..columns {
-        for r in 0..rows {
-            count += propagate(grid, c as i32, r as i32);
+    for c in 0..grid.num_cols {
+        for r in 0..grid.num_rows {
+            count += propagate(grid, &Position::new(r, c));
         }
     }
 
-    for c in 0..columns {
-        for r in 0..rows {
-            let value = grid[r][c];
-            if value == -1 {
-                grid[r][c] = 0;
+    for c in 0..grid.num_cols {
+        for r in 0..grid.num_rows {
+            let position = Position::new(r, c);
+            if grid[&position] == -1 {
+                grid[&position] = 0;
             }
         }
     }
@@ -117,22 +101,18 @@ fn explode(grid: &mut Vec<Vec<i32>>) -> u64 {
     return count;
 }
 
-fn propagate(grid: &mut Vec<Vec<i32>>, ic: i32, ir: i32) -> u64 {
-    let rows = grid.len() as i32;
-    let columns = grid[0].len() as i32;
-
+fn propagate(grid: &mut Grid<i32>, position: &Position) -> u64 {
     let mut count = 0;
-    if grid[ir as usize][ic as usize] > 9 {
+    if grid[position] > 9 {
         count += 1;
-        grid[ir as usize][ic as usize] = -1;
-
-        for nc in (ic - 1).max(0)..(ic + 2).min(columns) {
-            for nr in (ir - 1).max(0)..(ir + 2).min(rows) {
-                let value = grid[nr as usize][nc as usize];
-                if value >= 0 {
-                    grid[nr as usize][nc as usize] = value + 1;
-                    count += propagate(grid, nc, nr);
-                }
+        grid[position] = -1;
+
+        for neighbour in position.neighbours_with(grid.num_rows, grid.num_cols, Neighborhood::Moore)
+        {
+            let value = grid[&neighbour];
+            if value >= 0 {
+                grid[&neighbour] = value + 1;
+                count += propagate(grid, &neighbour);
             }
         }
     }
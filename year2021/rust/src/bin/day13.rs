@@ -7,6 +7,8 @@ use itertools::Itertools;
 use recap::Recap;
 use serde::Deserialize;
 
+use year2021::ocr::recognize_grid;
+
 #[derive(Debug, PartialEq, Eq, Hash, Deserialize, Recap)]
 #[recap(regex = r#"(?P<x>\d+),(?P<y>\d+)"#)]
 struct Point {
@@ -44,7 +46,7 @@ fn part1() -> Option<u64> {
     return Some(points.len() as u64);
 }
 
-fn part2() -> Option<u64> {
+fn part2() -> Option<String> {
     let instant = Instant::now();
     let (points, folds) = read_input()?;
 
@@ -60,19 +62,13 @@ fn part2() -> Option<u64> {
     let max_x = points.iter().map(|p| p.x).max()?;
     let max_y = points.iter().map(|p| p.y).max()?;
 
-    for y in 0..max_y + 1 {
-        for x in 0..max_x + 1 {
-            if points.contains(&Point { x, y }) {
-                print!("#");
-            } else {
-                print!(".");
-            }
-        }
-        println!()
+    let mut cells = vec![vec![false; max_x as usize + 1]; max_y as usize + 1];
+    for p in &points {
+        cells[p.y as usize][p.x as usize] = true;
     }
 
     println!("[part2] time={:?}", instant.elapsed());
-    return None;
+    return recognize_grid(&cells);
 }
 
 fn read_input() -> Option<(Vec<Point>, Vec<Fold>)> {
@@ -1,32 +1,65 @@
-use std::cmp::Ordering;
-use std::collections::{BinaryHeap, HashMap};
+use std::borrow::Borrow;
 use std::fs;
+use std::hash::{Hash, Hasher};
 use std::time::Instant;
 
-use itertools::Itertools;
-use year2021::grid::{Grid, Position};
+use year2021::grid::{Grid, GridView, Position};
+use year2021::search::{astar_with, HasNeighbors};
+use year2021::tiled::TiledGrid;
 
-#[derive(Debug, Clone, Hash, Eq, PartialEq)]
-struct Node {
-    pos: Position,
-    cost: usize,
+type Risks<'a> = TiledGrid<'a, usize>;
+
+/// A position in the cave, compared by position only.
+#[derive(Clone)]
+struct Location<'a> {
+    position: Position,
+    risks: &'a Risks<'a>,
+}
+
+impl<'a> Location<'a> {
+    fn target(&self) -> Position {
+        return Position::new(self.risks.num_rows() - 1, self.risks.num_cols() - 1);
+    }
+}
+
+impl<'a> PartialEq for Location<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        return self.position == other.position;
+    }
 }
 
-impl Node {
-    pub fn new(pos: Position, cost: usize) -> Self {
-        Node { pos, cost }
+impl<'a> Eq for Location<'a> {}
+
+impl<'a> Hash for Location<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.position.hash(state);
     }
 }
 
-impl Ord for Node {
-    fn cmp(&self, other: &Self) -> Ordering {
-        other.cost.cmp(&self.cost)
+impl<'a> Borrow<Position> for Location<'a> {
+    fn borrow(&self) -> &Position {
+        return &self.position;
     }
 }
 
-impl PartialOrd for Node {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+impl<'a> HasNeighbors for Location<'a> {
+    fn neighbors(&self) -> impl Iterator<Item = (Self, usize)> {
+        return self
+            .position
+            .neighbours(self.risks.num_rows(), self.risks.num_cols())
+            .map(|position| {
+                let location = Location {
+                    position,
+                    risks: self.risks,
+                };
+                (location, self.risks.value(&position))
+            });
+    }
+
+    fn heuristic(&self) -> usize {
+        // every step costs at least one.
+        let target = self.target();
+        return (target.row - self.position.row) + (target.col - self.position.col);
     }
 }
 
@@ -61,75 +94,23 @@ fn read_input() -> Grid<usize> {
     let filename = "res/input15.txt";
     let contents = fs::read_to_string(filename).unwrap();
 
-    let grid = contents
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(|c| c.to_string().parse::<usize>().unwrap())
-                .collect_vec()
-        })
-        .collect_vec();
-
-    let num_rows = grid.len();
-    let num_cols = grid[0].len();
-    return Grid::new(grid, num_rows, num_cols);
+    return Grid::parse(&contents, |c| c.to_digit(10).unwrap() as usize).unwrap();
 }
 
 fn calc_path(multiplier: usize, grid: &Grid<usize>) -> usize {
-    let target = Position::new(
-        multiplier * grid.num_rows - 1,
-        multiplier * grid.num_cols - 1,
-    );
-
-    let mut node_queue: BinaryHeap<Node> = BinaryHeap::new();
-
-    node_queue.push(Node::new(
-        Position::new(0, 1),
-        calc_cost(grid, &Position::new(0, 1)),
-    ));
-    node_queue.push(Node::new(
-        Position::new(1, 0),
-        calc_cost(grid, &Position::new(1, 0)),
-    ));
-
-    let mut memory: HashMap<Position, usize> = HashMap::new();
-    memory.insert(Position::new(0, 0), 0);
-
-    while let Some(node) = node_queue.pop() {
-        if node.pos == target {
-            return node.cost;
-        }
-
-        if let Some(known_cost) = memory.get(&node.pos) {
-            if *known_cost <= node.cost {
-                continue;
-            }
-        }
-
-        for neighbour in node
-            .pos
-            .neighbours(multiplier * grid.num_rows, multiplier * grid.num_cols)
-        {
-            let cost = node.cost + calc_cost(grid, &neighbour);
-
-            if let Some(known_cost) = memory.get(&neighbour) {
-                if *known_cost <= cost {
-                    continue;
-                }
-            }
-
-            node_queue.push(Node::new(neighbour, cost));
-            memory.insert(node.pos.clone(), node.cost);
-        }
-    }
-
-    unreachable!();
-}
-
-fn calc_cost(grid: &Grid<usize>, position: &Position) -> usize {
-    let grid_cost =
-        grid[&Position::new(position.row % grid.num_rows, position.col % grid.num_cols)];
-    let cost =
-        (grid_cost - 1 + (position.row / grid.num_rows) + (position.col / grid.num_cols)) % 9 + 1;
-    return cost;
+    // every tile to the right or down has its risk increased by one, wrapping from 9 back to 1.
+    let risks = TiledGrid::new(grid, multiplier, multiplier, |risk, tile| {
+        (risk - 1 + tile.row as usize + tile.col as usize) % 9 + 1
+    });
+    let start = Location {
+        position: Position::new(0, 0),
+        risks: &risks,
+    };
+    let target = start.target();
+    // a dense cost per position is much faster than hashing every location.
+    let best = Grid::filled(risks.num_rows(), risks.num_cols(), None);
+
+    return astar_with(start, |location| location.position == target, best)
+        .unwrap()
+        .dist_start;
 }
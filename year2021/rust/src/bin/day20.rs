@@ -3,9 +3,12 @@ use std::time::Instant;
 
 use itertools::Itertools;
 
-use year2021::grid::{Grid, Position};
+use year2021::automaton::{run, SparseAutomaton};
+use year2021::grid::Grid;
+use year2021::sparse::{Point, SparseGrid};
 
-type Image = Grid<bool>;
+/// Lit pixels, the default value is the state of the infinite background.
+type Image = SparseGrid<bool>;
 
 struct Input {
     pub image: Image,
@@ -33,12 +36,8 @@ fn read_input() -> Input {
         .collect_vec();
     lines.next().unwrap();
 
-    let values = lines
-        .map(|line| line.chars().map(|c| c == '#').collect_vec())
-        .collect_vec();
-    let num_rows = values.len();
-    let num_cols = values[0].len();
-    let image = Grid::new(values, num_rows, num_cols);
+    let pixels = Grid::parse(&lines.join("\n"), |c| c == '#').unwrap();
+    let image = SparseGrid::from_grid(&pixels, Point::new(0, 0), false);
 
     return Input { image, enhancement };
 }
@@ -46,12 +45,10 @@ fn read_input() -> Input {
 fn part1(input: &Input) -> Option<usize> {
     let instant = Instant::now();
 
-    let mut image = input.image.clone();
-    image = enhance(&image, &input.enhancement, 0);
-    image = enhance(&image, &input.enhancement, 1);
+    let image = run(enhancer(input), 2).grid;
 
-    // display(&image);
-    let lit = image.iter().filter(|v| **v).count();
+    year2021::aoc_trace!("image:\n{}", image.render(|v| if *v { '#' } else { '.' }));
+    let lit = image.iter().filter(|(_, v)| **v).count();
     println!("[part1] time={:?}", instant.elapsed());
     return Some(lit);
 }
@@ -59,72 +56,31 @@ fn part1(input: &Input) -> Option<usize> {
 fn part2(input: &Input) -> Option<usize> {
     let instant = Instant::now();
 
-    let mut image = input.image.clone();
-    for _ in 0..25 {
-        image = enhance(&image, &input.enhancement, 0);
-        image = enhance(&image, &input.enhancement, 1);
-    }
+    let image = run(enhancer(input), 50).grid;
 
-    // display(&image);
-    let lit = image.iter().filter(|v| **v).count();
+    year2021::aoc_trace!("image:\n{}", image.render(|v| if *v { '#' } else { '.' }));
+    let lit = image.iter().filter(|(_, v)| **v).count();
     println!("[part2] time={:?}", instant.elapsed());
     return Some(lit);
 }
 
-fn enhance(image: &Image, enhancement: &Vec<bool>, default: usize) -> Image {
-    let expand = 1;
-    let mut new_image = Grid::new(
-        vec![vec![false; image.num_cols + 2 * expand]; image.num_rows + 2 * expand],
-        image.num_rows + 2 * expand,
-        image.num_cols + 2 * expand,
-    );
-    for r in 0..image.num_rows + 2 * expand {
-        for c in 0..image.num_cols + 2 * expand {
-            let value = read(
-                image,
-                r as isize - expand as isize,
-                c as isize - expand as isize,
-                default,
-            );
-            let new_value = enhancement[value as usize];
-            // println!("position={:?} value={}", (r, c), value);
-            new_image[&Position::new(r, c)] = new_value
-        }
-    }
-    return new_image;
+/// Every step enhances the image, including its infinite background.
+fn enhancer(input: &Input) -> SparseAutomaton<bool> {
+    let enhancement = input.enhancement.clone();
+    return SparseAutomaton::new(input.image.clone(), move |image, point| {
+        enhancement[read(image, point)]
+    });
 }
 
-fn read(image: &Image, row: isize, col: isize, default: usize) -> usize {
+fn read(image: &Image, point: &Point) -> usize {
     let mut value = 0;
-    for r in row - 1..row + 2 {
-        for c in col - 1..col + 2 {
+    for r in point.row - 1..point.row + 2 {
+        for c in point.col - 1..point.col + 2 {
             value <<= 1;
-            if r >= 0 && r < image.num_rows as isize && c >= 0 && c < image.num_cols as isize {
-                if image[&Position::new(r as usize, c as usize)] {
-                    value += 1;
-                }
-            } else {
-                value += default;
+            if image[&Point::new(r, c)] {
+                value += 1;
             }
         }
     }
     return value;
 }
-
-fn display(image: &Image) {
-    for r in 0..image.num_rows {
-        for c in 0..image.num_cols {
-            print!(
-                "{}",
-                if image[&Position::new(r, c)] {
-                    '#'
-                } else {
-                    '.'
-                }
-            );
-        }
-        println!();
-    }
-
-    println!("{} by {}", image.num_rows, image.num_cols);
-}
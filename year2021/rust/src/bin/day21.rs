@@ -75,7 +75,15 @@ impl Player {
         }
         self.score += new_location;
         self.location = new_location;
-        // println!("Player {} rolls {}+{}+{} and moves to space {} for a total score of {}", self.id, roll1, roll2, roll3, self.location, self.score);
+        year2021::aoc_trace!(
+            "Player {} rolls {}+{}+{} and moves to space {} for a total score of {}",
+            self.id,
+            roll1,
+            roll2,
+            roll3,
+            self.location,
+            self.score
+        );
         return self.score >= 1000;
     }
 
@@ -115,7 +123,7 @@ fn part1(input: &Input) -> Option<usize> {
     let mut die = Dice::new();
     let mut players = Vec::<Player>::new();
     for player in input {
-        // println!("player={:?}", player);
+        year2021::aoc_debug!("player={:?}", player);
         players.push(Player::new(player));
     }
 
@@ -130,7 +138,7 @@ fn part1(input: &Input) -> Option<usize> {
         }
         if winner {
             let loser = players.iter().filter(|p| p.score < 1000).next().unwrap();
-            // println!("loser score={} rolls={} ", loser.score, die.roll_count);
+            year2021::aoc_debug!("loser score={} rolls={}", loser.score, die.roll_count);
             result = die.roll_count * loser.score;
             break;
         }
@@ -166,7 +174,6 @@ fn part2(input: &Input) -> Option<usize> {
 
     let mut results = HashMap::<usize, u128>::new();
     let mut player_index = 0;
-    // let mut count = 0;
     while !universe.is_empty() {
         let mut new_universe = HashMap::<Vec<Player>, u128>::new();
         for (player_state, count) in &universe {
@@ -182,19 +189,8 @@ fn part2(input: &Input) -> Option<usize> {
             }
         }
 
-        // if count < 4 {
-        //     let mut player_universe = HashMap::<Player, u128>::new();
-        //     for (player_state, count) in &new_universe {
-        //         for p in player_state {
-        //             increment(&mut player_universe, p.clone(), *count);
-        //         }
-        //     }
-        //     println!("player={} universe={:?}", player_index, player_universe)
-        // }
-
         universe = new_universe;
         player_index = (player_index + 1) % player_count;
-        // count += 1;
     }
     println!("results={:?}", results);
 
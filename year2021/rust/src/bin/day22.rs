@@ -100,7 +100,7 @@ fn part2(input: &Input) -> Option<u128> {
             instruction.y1..=instruction.y2,
             instruction.z1..=instruction.z2,
         );
-        // println!("op={:?} region={:?} ", instruction.op, region);
+        year2021::aoc_trace!("op={:?} region={:?}", instruction.op, region);
         if instruction.op == Op::On {
             for a in add_region(&regions, region) {
                 regions.push_back(a);
@@ -1,15 +1,12 @@
-use std::cmp::{max, min, Ordering};
-use std::collections::{BinaryHeap, HashMap};
-use std::fmt::{Debug, Display, Formatter};
+use std::cmp::{max, min};
+use std::fmt::{Display, Formatter};
 use std::fs;
-use std::mem::discriminant;
 use std::slice::Iter;
 use std::time::Instant;
 
 use itertools::Itertools;
-use regex::internal::Char;
 
-use year2021::search::{HasNeighbors, SearchNode};
+use year2021::search::{astar, HasNeighbors};
 
 fn abs_diff(a: usize, b: usize) -> usize {
     return if a > b { a - b } else { b - a };
@@ -151,32 +148,11 @@ fn read_input(file: &str) -> Input {
 fn part1(input: &Input) -> Option<usize> {
     let instant = Instant::now();
 
-    let mut search = BinaryHeap::<Node>::new();
-    search.push(Node::new(input.clone(), 0));
-
-    let mut count = 0 as usize;
-    while let Some(node) = search.pop() {
-        if node.state.complete() {
-            print!("{}", node.state);
-            return Some(node.cost);
-        }
-
-        count += 1;
-        if count % 100000 == 0 {
-            println!("queue_size={}", search.len());
-            println!("cost={} dist={}", node.cost, node.dist);
-            print!("{}", node.state);
-            println!();
-        }
-
-        for (state, move_cost) in node.state.possible_moves() {
-            let cost = node.cost + move_cost;
-            search.push(Node::new(state, cost));
-        }
-    }
+    let end = astar(input.clone(), Burrow::complete)?;
+    print!("{}", end.value);
 
     println!("[part1] time={:?}", instant.elapsed());
-    return None;
+    return Some(end.dist_start);
 }
 
 fn part2(input: &Input) -> Option<usize> {
@@ -186,61 +162,6 @@ fn part2(input: &Input) -> Option<usize> {
     return None;
 }
 
-#[derive(PartialEq, Eq)]
-struct Node {
-    state: Burrow,
-    cost: usize,
-    dist: usize,
-}
-
-impl Node {
-    pub fn new(state: Burrow, cost: usize) -> Self {
-        let mut dist = 0usize;
-
-        for column in 0..state.rooms.len() {
-            let room = state.rooms[column];
-            let mut trapped = false;
-            for depth in (0..room.spaces.len()).rev() {
-                if let Some(amphipod) = room.spaces[depth] {
-                    if amphipod != room.amphipod {
-                        trapped = true;
-                        dist += (depth + 1) * amphipod.movement_cost(); // move out
-                        dist += (depth + 1) * room.amphipod.movement_cost(); // something needs to move in
-                        dist += abs_diff(room.amphipod.target_column(), amphipod.target_column())
-                            * amphipod.movement_cost(); // move laterally
-                    } else if trapped {
-                        dist += 2 * (depth + 1) * amphipod.movement_cost(); // move out and back in
-                        dist += 2 * amphipod.movement_cost(); // move out of the way
-                    }
-                } else {
-                    dist += (depth + 1) * room.amphipod.movement_cost(); // something needs to move in
-                }
-            }
-        }
-
-        for column in 0..state.hallway.len() {
-            if let Some(amphipod) = state.hallway[column] {
-                dist += abs_diff(column, amphipod.target_column()) * amphipod.movement_cost();
-                // move laterally
-            }
-        }
-
-        return Node { state, cost, dist };
-    }
-}
-
-impl Ord for Node {
-    fn cmp(&self, other: &Self) -> Ordering {
-        (other.cost + other.dist).cmp(&(self.cost + self.dist))
-    }
-}
-
-impl PartialOrd for Node {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
 impl Display for Burrow {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         fn write_space(
@@ -320,8 +241,43 @@ impl Display for Amphipod {
 }
 
 impl HasNeighbors for Burrow {
-    fn neighbors(&self) -> Vec<SearchNode<Burrow>> {
-        todo!()
+    fn neighbors(&self) -> impl Iterator<Item = (Burrow, usize)> {
+        return self.possible_moves().into_iter();
+    }
+
+    /// The cost of moving every amphipod to its room if nothing were in the way.
+    fn heuristic(&self) -> usize {
+        let mut dist = 0usize;
+
+        for column in 0..self.rooms.len() {
+            let room = self.rooms[column];
+            let mut trapped = false;
+            for depth in (0..room.spaces.len()).rev() {
+                if let Some(amphipod) = room.spaces[depth] {
+                    if amphipod != room.amphipod {
+                        trapped = true;
+                        dist += (depth + 1) * amphipod.movement_cost(); // move out
+                        dist += (depth + 1) * room.amphipod.movement_cost(); // something needs to move in
+                        dist += abs_diff(room.amphipod.target_column(), amphipod.target_column())
+                            * amphipod.movement_cost(); // move laterally
+                    } else if trapped {
+                        dist += 2 * (depth + 1) * amphipod.movement_cost(); // move out and back in
+                        dist += 2 * amphipod.movement_cost(); // move out of the way
+                    }
+                } else {
+                    dist += (depth + 1) * room.amphipod.movement_cost(); // something needs to move in
+                }
+            }
+        }
+
+        for column in 0..self.hallway.len() {
+            if let Some(amphipod) = self.hallway[column] {
+                dist += abs_diff(column, amphipod.target_column()) * amphipod.movement_cost();
+                // move laterally
+            }
+        }
+
+        return dist;
     }
 }
 
@@ -1,12 +1,16 @@
 use std::fs;
 use std::time::Instant;
 
-use itertools::Itertools;
-
-use year2021::grid::{Grid, Position};
+use year2021::automaton::{run_until_stable, GridAutomaton};
+use year2021::grid::{Grid, Neighborhood, Position};
 
 type Input = Grid<char>;
 
+const EAST: Neighborhood = Neighborhood::Custom(&[(0, 1)]);
+const SOUTH: Neighborhood = Neighborhood::Custom(&[(1, 0)]);
+const WEST: Neighborhood = Neighborhood::Custom(&[(0, -1)]);
+const NORTH: Neighborhood = Neighborhood::Custom(&[(-1, 0)]);
+
 fn main() {
     let start = Instant::now();
     let input = read_input("res/input25.txt");
@@ -19,31 +23,18 @@ fn read_input(file: &str) -> Input {
     let filename = file;
     let contents = fs::read_to_string(filename).unwrap();
 
-    let grid = contents
-        .lines()
-        .map(|line| line.chars().collect_vec())
-        .collect_vec();
-
-    let num_rows = grid.len();
-    let num_cols = grid[0].len();
-    return Grid::new(grid, num_rows, num_cols);
+    return contents.parse().unwrap();
 }
 
 fn part1(_input: &Input) -> Option<usize> {
     let instant = Instant::now();
 
-    let mut grid = _input.clone();
-    let mut turns = 1;
-    while step(&mut grid) {
-        turns += 1;
-        // println!("turns={}", turns);
-        display(&grid);
-    }
-
-    println!("turns={}", turns);
+    let herds = GridAutomaton::new(_input.clone(), move_herd('>', EAST, WEST))
+        .then(move_herd('v', SOUTH, NORTH));
+    let (_, turns) = run_until_stable(herds);
 
     println!("[part1] time={:?}", instant.elapsed());
-    return None;
+    return Some(turns);
 }
 
 fn part2(_input: &Input) -> Option<usize> {
@@ -53,71 +44,34 @@ fn part2(_input: &Input) -> Option<usize> {
     return None;
 }
 
-fn step(grid: &mut Grid<char>) -> bool {
-    let mut moved = false;
-
-    // Step right
-    for r in 0..grid.num_rows {
-        for c in (0..grid.num_cols).rev() {
-            let start = Position::new(r, c);
-            let end = Position::new(r, (c + 1) % grid.num_cols);
-            if grid[&start] == '>' && grid[&end] == '.' {
-                // println!("({},{})->({},{})", start.row, start.col, end.row, end.col);
-                grid[&end] = 'h';
-                grid[&start] = 't';
-                moved = true;
-            }
+/// Rule moving every cucumber of `herd` one step `ahead` at once, if that location is free.
+fn move_herd(
+    herd: char,
+    ahead: Neighborhood,
+    behind: Neighborhood,
+) -> impl Fn(&Grid<char>, &Position) -> char {
+    return move |grid, position| {
+        let value = grid[position];
+        if value == '.' && grid[&next_position(grid, position, behind)] == herd {
+            return herd;
         }
-    }
-
-    // clear cucumber trails
-    for r in 0..grid.num_rows {
-        for c in 0..grid.num_cols {
-            let start = Position::new(r, c);
-            if grid[&start] == 't' {
-                grid[&start] = '.';
-            } else if grid[&start] == 'h' {
-                grid[&start] = '>';
-            }
+        if value == herd && grid[&next_position(grid, position, ahead)] == '.' {
+            return '.';
         }
-    }
-
-    // Step down
-    for c in 0..grid.num_cols {
-        for r in (0..grid.num_rows).rev() {
-            let start = Position::new(r, c);
-            let end = Position::new((r + 1) % grid.num_rows, c);
-            if grid[&start] == 'v' && grid[&end] == '.' {
-                // println!("({},{})->({},{})", start.row, start.col, end.row, end.col);
-                grid[&end] = 'h';
-                grid[&start] = 't';
-                moved = true;
-            }
-        }
-    }
-
-    // clear cucumber trails
-    for r in 0..grid.num_rows {
-        for c in 0..grid.num_cols {
-            let start = Position::new(r, c);
-            if grid[&start] == 't' {
-                grid[&start] = '.';
-            } else if grid[&start] == 'h' {
-                grid[&start] = 'v';
-            }
-        }
-    }
+        return value;
+    };
+}
 
-    return moved;
+fn next_position(grid: &Grid<char>, start: &Position, direction: Neighborhood) -> Position {
+    return start
+        .neighbours_with(grid.num_rows, grid.num_cols, direction)
+        .wrapping()
+        .next()
+        .unwrap();
 }
 
+#[allow(dead_code)]
 fn display(grid: &Grid<char>) {
-    // println!("{}x{}", grid.num_rows, grid.num_cols);
-    for r in 0..grid.num_rows {
-        for c in 0..grid.num_cols {
-            print!("{}", grid[&Position::new(r, c)]);
-        }
-        println!();
-    }
+    println!("{}", grid);
     println!();
 }
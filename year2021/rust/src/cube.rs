@@ -96,7 +96,7 @@ impl Region {
         let mut splits_z = VecDeque::<Region>::new();
         splits_x.push_back(other.clone());
 
-        // println!("splits_x={:?} results={:?}", splits_x, results);
+        crate::aoc_trace!("splits_x={:?} results={:?}", splits_x, results);
 
         while let Some(region) = splits_x.pop_front() {
             if !self.intersect(&region) {
@@ -174,7 +174,7 @@ impl Region {
             }
         }
 
-        // println!("splits_y={:?} results={:?}", splits_y, results);
+        crate::aoc_trace!("splits_y={:?} results={:?}", splits_y, results);
 
         while let Some(region) = splits_y.pop_front() {
             if !self.intersect(&region) {
@@ -252,7 +252,7 @@ impl Region {
             }
         }
 
-        // println!("splits_z={:?} results={:?}", splits_z, results);
+        crate::aoc_trace!("splits_z={:?} results={:?}", splits_z, results);
 
         while let Some(region) = splits_z.pop_front() {
             if !self.intersect(&region) {
@@ -330,7 +330,7 @@ impl Region {
             }
         }
 
-        // println!("splits_x={:?} results={:?}", splits_x, results);
+        crate::aoc_trace!("splits_x={:?} results={:?}", splits_x, results);
 
         while let Some(region) = splits_x.pop_front() {
             if !self.intersect(&region) {
@@ -338,7 +338,7 @@ impl Region {
             }
         }
 
-        // println!("results={:?}", results);
+        crate::aoc_trace!("results={:?}", results);
 
         return results;
     }
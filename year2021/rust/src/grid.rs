@@ -1,14 +1,92 @@
-use std::ops::{Index, IndexMut};
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut, Range};
+use std::str::FromStr;
 
+/// A rectangular grid, stored row by row in a single `Vec`.
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
 pub struct Grid<T> {
-    values: Vec<Vec<T>>,
+    values: Vec<T>,
     pub num_rows: usize,
     pub num_cols: usize,
 }
 
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ParseGridError {
+    /// A row with a different length than the first row.
+    RaggedRow {
+        row: usize,
+        expected: usize,
+        actual: usize,
+    },
+}
+
+impl Display for ParseGridError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        return match self {
+            ParseGridError::RaggedRow {
+                row,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "row {} has {} columns, expected {}",
+                row, actual, expected
+            ),
+        };
+    }
+}
+
+impl std::error::Error for ParseGridError {}
+
 impl<T> Grid<T> {
-    pub fn new(values: Vec<Vec<T>>, num_rows: usize, num_cols: usize) -> Self {
+    /// Create a grid from its rows, which must all have the same length.
+    pub fn new(values: Vec<Vec<T>>) -> Self {
+        return Grid::try_new(values).unwrap();
+    }
+
+    fn try_new(rows: Vec<Vec<T>>) -> Result<Self, ParseGridError> {
+        let num_rows = rows.len();
+        let num_cols = rows.first().map_or(0, |row| row.len());
+
+        let mut values = Vec::with_capacity(num_rows * num_cols);
+        for (row, row_values) in rows.into_iter().enumerate() {
+            if row_values.len() != num_cols {
+                return Err(ParseGridError::RaggedRow {
+                    row,
+                    expected: num_cols,
+                    actual: row_values.len(),
+                });
+            }
+            values.extend(row_values);
+        }
+
+        return Ok(Grid {
+            values,
+            num_rows,
+            num_cols,
+        });
+    }
+
+    pub fn filled(num_rows: usize, num_cols: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        return Grid {
+            values: vec![value; num_rows * num_cols],
+            num_rows,
+            num_cols,
+        };
+    }
+
+    /// Create a grid with the value of every cell given by `f`.
+    pub fn from_fn(num_rows: usize, num_cols: usize, mut f: impl FnMut(Position) -> T) -> Self {
+        let mut values = Vec::with_capacity(num_rows * num_cols);
+        for row in 0..num_rows {
+            for col in 0..num_cols {
+                values.push(f(Position::new(row, col)));
+            }
+        }
         return Grid {
             values,
             num_rows,
@@ -16,12 +94,294 @@ impl<T> Grid<T> {
         };
     }
 
+    /// Parse a grid with one row per line, mapping every character with `f`.
+    pub fn parse(s: &str, mut f: impl FnMut(char) -> T) -> Result<Self, ParseGridError> {
+        let values = s
+            .lines()
+            .map(|line| line.chars().map(&mut f).collect())
+            .collect();
+        return Grid::try_new(values);
+    }
+
+    /// Render the grid with one line per row, mapping every cell to a character with `f`.
+    pub fn render(&self, f: impl Fn(&T) -> char) -> String {
+        return self
+            .rows()
+            .map(|row| row.iter().map(&f).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n");
+    }
+
     pub fn contains(&self, position: &Position) -> bool {
         return position.row < self.num_rows && position.col < self.num_cols;
     }
 
+    pub fn get(&self, position: &Position) -> Option<&T> {
+        if !self.contains(position) {
+            return None;
+        }
+        return self.values.get(position.row * self.num_cols + position.col);
+    }
+
+    pub fn get_mut(&mut self, position: &Position) -> Option<&mut T> {
+        if !self.contains(position) {
+            return None;
+        }
+        return self
+            .values
+            .get_mut(position.row * self.num_cols + position.col);
+    }
+
+    /// All values, row by row.
     pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
-        return self.values.iter().flat_map(|row| row.iter());
+        return self.values.iter();
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> + '_ {
+        return self.values.iter_mut();
+    }
+
+    /// All positions of the grid, row by row.
+    pub fn iter_positions(&self) -> impl Iterator<Item = Position> {
+        let num_cols = self.num_cols;
+        // a single range is much cheaper to drive than nested ones.
+        return (0..self.num_rows * num_cols)
+            .map(move |index| Position::new(index / num_cols, index % num_cols));
+    }
+
+    /// All values with their position, row by row.
+    pub fn enumerate(&self) -> impl Iterator<Item = (Position, &T)> + '_ {
+        return self.iter_positions().zip(self.values.iter());
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        return &self.values[row * self.num_cols..(row + 1) * self.num_cols];
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        return &mut self.values[row * self.num_cols..(row + 1) * self.num_cols];
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        return (0..self.num_rows).map(move |row| self.row(row));
+    }
+
+    /// The values of a column, from top to bottom.
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        assert!(col < self.num_cols, "column {} out of bounds", col);
+        return self.values[col..].iter().step_by(self.num_cols);
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T> + '_> + '_ {
+        return (0..self.num_cols).map(move |col| self.column(col));
+    }
+
+    /// A read-only view of the cells in the given rows and columns.
+    pub fn sub_grid(&self, rows: Range<usize>, cols: Range<usize>) -> SubGrid<'_, T> {
+        assert!(
+            rows.start <= rows.end && rows.end <= self.num_rows,
+            "rows {:?} out of bounds",
+            rows
+        );
+        assert!(
+            cols.start <= cols.end && cols.end <= self.num_cols,
+            "columns {:?} out of bounds",
+            cols
+        );
+        return SubGrid {
+            grid: self,
+            row_offset: rows.start,
+            col_offset: cols.start,
+            num_rows: rows.len(),
+            num_cols: cols.len(),
+        };
+    }
+
+    /// Mirror the grid along its diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        return Grid::from_fn(self.num_cols, self.num_rows, |p| {
+            self[&Position::new(p.col, p.row)].clone()
+        });
+    }
+
+    /// Rotate the grid by 90° clockwise.
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        return Grid::from_fn(self.num_cols, self.num_rows, |p| {
+            self[&Position::new(self.num_rows - 1 - p.col, p.row)].clone()
+        });
+    }
+
+    /// Rotate the grid by 90° counterclockwise.
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        return Grid::from_fn(self.num_cols, self.num_rows, |p| {
+            self[&Position::new(p.col, self.num_cols - 1 - p.row)].clone()
+        });
+    }
+
+    /// Mirror the grid left to right.
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        return Grid::from_fn(self.num_rows, self.num_cols, |p| {
+            self[&Position::new(p.row, self.num_cols - 1 - p.col)].clone()
+        });
+    }
+
+    /// Mirror the grid top to bottom.
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        return Grid::from_fn(self.num_rows, self.num_cols, |p| {
+            self[&Position::new(self.num_rows - 1 - p.row, p.col)].clone()
+        });
+    }
+
+    fn offset(&self, position: &Position) -> usize {
+        assert!(
+            self.contains(position),
+            "position {:?} out of bounds for a {}x{} grid",
+            position,
+            self.num_rows,
+            self.num_cols
+        );
+        return position.row * self.num_cols + position.col;
+    }
+}
+
+/// A rectangular part of a [`Grid`], see [`Grid::sub_grid`].
+#[derive(Debug, Clone, Copy)]
+pub struct SubGrid<'a, T> {
+    grid: &'a Grid<T>,
+    row_offset: usize,
+    col_offset: usize,
+    pub num_rows: usize,
+    pub num_cols: usize,
+}
+
+impl<'a, T> SubGrid<'a, T> {
+    pub fn contains(&self, position: &Position) -> bool {
+        return position.row < self.num_rows && position.col < self.num_cols;
+    }
+
+    pub fn row(&self, row: usize) -> &'a [T] {
+        assert!(row < self.num_rows, "row {} out of bounds", row);
+        let cols = self.col_offset..self.col_offset + self.num_cols;
+        return &self.grid.row(self.row_offset + row)[cols];
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + '_ {
+        return (0..self.num_rows).map(move |row| self.row(row));
+    }
+
+    /// All values, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + '_ {
+        return self.rows().flatten();
+    }
+
+    /// Copy the viewed cells into a grid of their own.
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        return Grid::from_fn(self.num_rows, self.num_cols, |p| self[&p].clone());
+    }
+}
+
+impl<'a, T> Index<&Position> for SubGrid<'a, T> {
+    type Output = T;
+
+    fn index(&self, position: &Position) -> &Self::Output {
+        assert!(
+            self.contains(position),
+            "position {:?} out of bounds for a {}x{} sub-grid",
+            position,
+            self.num_rows,
+            self.num_cols
+        );
+        return &self.grid[&Position::new(
+            self.row_offset + position.row,
+            self.col_offset + position.col,
+        )];
+    }
+}
+
+/// Read access to the values of a rectangular grid, such as a [`Grid`] or a view on one.
+pub trait GridView {
+    type Value;
+
+    fn num_rows(&self) -> usize;
+
+    fn num_cols(&self) -> usize;
+
+    fn value(&self, position: &Position) -> Self::Value;
+
+    fn contains(&self, position: &Position) -> bool {
+        return position.row < self.num_rows() && position.col < self.num_cols();
+    }
+}
+
+impl<T: Clone> GridView for Grid<T> {
+    type Value = T;
+
+    fn num_rows(&self) -> usize {
+        return self.num_rows;
+    }
+
+    fn num_cols(&self) -> usize {
+        return self.num_cols;
+    }
+
+    fn value(&self, position: &Position) -> T {
+        return self[position].clone();
+    }
+}
+
+impl<'a, T: Clone> GridView for SubGrid<'a, T> {
+    type Value = T;
+
+    fn num_rows(&self) -> usize {
+        return self.num_rows;
+    }
+
+    fn num_cols(&self) -> usize {
+        return self.num_cols;
+    }
+
+    fn value(&self, position: &Position) -> T {
+        return self[position].clone();
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseGridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        return Grid::parse(s, |c| c);
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (index, row) in self.rows().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            for value in row {
+                write!(f, "{}", value)?;
+            }
+        }
+        return Ok(());
     }
 }
 
@@ -36,30 +396,99 @@ impl Position {
         Position { row, col }
     }
 
-    pub fn neighbours(&self, num_rows: usize, num_cols: usize) -> Vec<Position> {
-        let mut result = Vec::new();
+    /// The orthogonal neighbours of this position within a grid of the given size.
+    pub fn neighbours(&self, num_rows: usize, num_cols: usize) -> Neighbours {
+        return self.neighbours_with(num_rows, num_cols, Neighborhood::Orthogonal);
+    }
 
-        // Up
-        if self.row > 0 {
-            result.push(Position::new(self.row - 1, self.col));
-        }
+    /// The neighbours of this position in `neighborhood`, within a grid of the given size.
+    pub fn neighbours_with(
+        &self,
+        num_rows: usize,
+        num_cols: usize,
+        neighborhood: Neighborhood,
+    ) -> Neighbours {
+        return Neighbours {
+            position: *self,
+            num_rows,
+            num_cols,
+            offsets: neighborhood.offsets(),
+            wrap: false,
+            index: 0,
+        };
+    }
+}
 
-        // Down
-        if self.row < num_rows - 1 {
-            result.push(Position::new(self.row + 1, self.col));
-        }
+/// Offsets (row, column) of the cells around a position that count as its neighbours.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Neighborhood {
+    /// Up, down, left and right.
+    Orthogonal,
+    Diagonal,
+    /// All eight surrounding cells.
+    Moore,
+    Custom(&'static [(isize, isize)]),
+}
 
-        // Left
-        if self.col > 0 {
-            result.push(Position::new(self.row, self.col - 1));
-        }
+impl Neighborhood {
+    pub fn offsets(&self) -> &'static [(isize, isize)] {
+        return match self {
+            Neighborhood::Orthogonal => &[(-1, 0), (1, 0), (0, -1), (0, 1)],
+            Neighborhood::Diagonal => &[(-1, -1), (-1, 1), (1, -1), (1, 1)],
+            Neighborhood::Moore => &[
+                (-1, -1),
+                (-1, 0),
+                (-1, 1),
+                (0, -1),
+                (0, 1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+            ],
+            Neighborhood::Custom(offsets) => offsets,
+        };
+    }
+}
+
+/// Iterator over the neighbours of a position, see [`Position::neighbours_with`].
+#[derive(Debug, Clone)]
+pub struct Neighbours {
+    position: Position,
+    num_rows: usize,
+    num_cols: usize,
+    offsets: &'static [(isize, isize)],
+    wrap: bool,
+    index: usize,
+}
+
+impl Neighbours {
+    /// Wrap around the edges of the grid instead of skipping neighbours outside of it.
+    pub fn wrapping(mut self) -> Self {
+        self.wrap = true;
+        return self;
+    }
 
-        // Right
-        if self.col < num_cols - 1 {
-            result.push(Position::new(self.row, self.col + 1));
+    fn offset(&self, value: usize, delta: isize, size: usize) -> Option<usize> {
+        if self.wrap {
+            return Some((value as isize + delta).rem_euclid(size as isize) as usize);
         }
+        return value.checked_add_signed(delta).filter(|v| *v < size);
+    }
+}
+
+impl Iterator for Neighbours {
+    type Item = Position;
 
-        return result;
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((dr, dc)) = self.offsets.get(self.index) {
+            self.index += 1;
+            let row = self.offset(self.position.row, *dr, self.num_rows);
+            let col = self.offset(self.position.col, *dc, self.num_cols);
+            if let (Some(row), Some(col)) = (row, col) {
+                return Some(Position::new(row, col));
+            }
+        }
+        return None;
     }
 }
 
@@ -67,12 +496,133 @@ impl<T> Index<&Position> for Grid<T> {
     type Output = T;
 
     fn index(&self, position: &Position) -> &Self::Output {
-        return &self.values[position.row][position.col];
+        return &self.values[self.offset(position)];
     }
 }
 
 impl<T> IndexMut<&Position> for Grid<T> {
     fn index_mut(&mut self, position: &Position) -> &mut Self::Output {
-        return &mut self.values[position.row][position.col];
+        let offset = self.offset(position);
+        return &mut self.values[offset];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse("123\n456\n", |c| c.to_digit(10).unwrap()).unwrap();
+        assert_eq!((grid.num_rows, grid.num_cols), (2, 3));
+        assert_eq!(grid[&Position::new(1, 0)], 4);
+        assert_eq!(grid.to_string(), "123\n456");
+    }
+
+    #[test]
+    fn test_parse_ragged() {
+        assert_eq!(
+            "#..\n.#\n".parse::<Grid<char>>(),
+            Err(ParseGridError::RaggedRow {
+                row: 1,
+                expected: 3,
+                actual: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let corner = Position::new(0, 0);
+        assert_eq!(
+            corner.neighbours(3, 3).collect::<Vec<_>>(),
+            vec![Position::new(1, 0), Position::new(0, 1)]
+        );
+        assert_eq!(corner.neighbours_with(3, 3, Neighborhood::Moore).count(), 3);
+        assert_eq!(
+            Position::new(1, 1)
+                .neighbours_with(3, 3, Neighborhood::Diagonal)
+                .collect::<Vec<_>>(),
+            vec![
+                Position::new(0, 0),
+                Position::new(0, 2),
+                Position::new(2, 0),
+                Position::new(2, 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_neighbours_wrapping() {
+        let east = Neighborhood::Custom(&[(0, 1)]);
+        assert_eq!(
+            Position::new(0, 2)
+                .neighbours_with(3, 3, east)
+                .wrapping()
+                .collect::<Vec<_>>(),
+            vec![Position::new(0, 0)]
+        );
+        assert_eq!(
+            Position::new(0, 0)
+                .neighbours(3, 3)
+                .wrapping()
+                .collect::<Vec<_>>(),
+            vec![
+                Position::new(2, 0),
+                Position::new(1, 0),
+                Position::new(0, 2),
+                Position::new(0, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let grid = Grid::from_fn(2, 3, |p| p.row == p.col);
+        assert_eq!(grid.render(|v| if *v { '#' } else { '.' }), "#..\n.#.");
+        assert_eq!(Grid::filled(2, 2, 'x').to_string(), "xx\nxx");
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let mut grid: Grid<char> = "abc\ndef".parse().unwrap();
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        assert_eq!(grid.column(0).rev().collect::<String>(), "da");
+        assert_eq!(grid.columns().count(), 3);
+
+        grid.row_mut(0)[1] = 'x';
+        assert_eq!(grid.get(&Position::new(0, 1)), Some(&'x'));
+        assert_eq!(grid.get(&Position::new(0, 3)), None);
+        assert_eq!(grid.enumerate().nth(4), Some((Position::new(1, 1), &'e')));
+    }
+
+    #[test]
+    fn test_transforms() {
+        let grid: Grid<char> = "abc\ndef".parse().unwrap();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed");
+        assert_eq!(grid.flip_vertical().to_string(), "def\nabc");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+    }
+
+    #[test]
+    fn test_sub_grid() {
+        let grid: Grid<char> = "abcd\nefgh\nijkl".parse().unwrap();
+        let sub_grid = grid.sub_grid(1..3, 1..3);
+        assert_eq!((sub_grid.num_rows, sub_grid.num_cols), (2, 2));
+        assert_eq!(sub_grid[&Position::new(1, 0)], 'j');
+        assert_eq!(sub_grid.row(0), ['f', 'g']);
+        assert_eq!(sub_grid.iter().collect::<String>(), "fgjk");
+        assert_eq!(sub_grid.to_grid().to_string(), "fg\njk");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds() {
+        let grid = Grid::filled(2, 2, 0);
+        let _ = grid[&Position::new(0, 2)];
     }
 }
@@ -0,0 +1,229 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::grid::{Grid, GridView, Neighborhood, Position};
+
+/// Breadth-first distances from the nearest of `sources`, `None` for cells that cannot be reached.
+/// A step from one cell to a neighbour is only taken if `passable(from, to)` holds for their values.
+pub fn distances<G: GridView>(
+    grid: &G,
+    sources: &[Position],
+    neighborhood: Neighborhood,
+    passable: impl Fn(&G::Value, &G::Value) -> bool,
+) -> Grid<Option<usize>> {
+    let (num_rows, num_cols) = (grid.num_rows(), grid.num_cols());
+    let mut distances = Grid::filled(num_rows, num_cols, None);
+    let mut queue = VecDeque::new();
+    for source in sources {
+        if distances[source].is_none() {
+            distances[source] = Some(0);
+            queue.push_back(*source);
+        }
+    }
+
+    while let Some(position) = queue.pop_front() {
+        let distance = distances[&position].unwrap();
+        let value = grid.value(&position);
+        for neighbour in position.neighbours_with(num_rows, num_cols, neighborhood) {
+            if distances[&neighbour].is_none() && passable(&value, &grid.value(&neighbour)) {
+                distances[&neighbour] = Some(distance + 1);
+                queue.push_back(neighbour);
+            }
+        }
+    }
+
+    return distances;
+}
+
+/// All cells connected to `start` through cells for which `include` holds, in the order they were reached.
+pub fn flood_fill<G: GridView>(
+    grid: &G,
+    start: Position,
+    neighborhood: Neighborhood,
+    include: impl Fn(&G::Value) -> bool,
+) -> Vec<Position> {
+    if !include(&grid.value(&start)) {
+        return vec![];
+    }
+
+    let (num_rows, num_cols) = (grid.num_rows(), grid.num_cols());
+    let mut seen = Grid::filled(num_rows, num_cols, false);
+    let mut region = vec![start];
+    seen[&start] = true;
+
+    let mut index = 0;
+    while let Some(position) = region.get(index).copied() {
+        index += 1;
+        for neighbour in position.neighbours_with(num_rows, num_cols, neighborhood) {
+            if !seen[&neighbour] && include(&grid.value(&neighbour)) {
+                seen[&neighbour] = true;
+                region.push(neighbour);
+            }
+        }
+    }
+
+    return region;
+}
+
+/// Number of cell sides between `region` and the cells around it, including the edge of the grid.
+pub fn perimeter(region: &[Position]) -> usize {
+    let cells: HashSet<&Position> = region.iter().collect();
+    return region
+        .iter()
+        .map(|position| {
+            // neighbours outside of the grid are never part of the region.
+            let inside = position
+                .neighbours(usize::MAX, usize::MAX)
+                .filter(|neighbour| cells.contains(neighbour))
+                .count();
+            4 - inside
+        })
+        .sum();
+}
+
+/// Groups of connected cells, see [`components`].
+#[derive(Debug, Clone)]
+pub struct Components {
+    /// The component of every cell.
+    pub labels: Grid<usize>,
+    /// The number of cells in each component.
+    pub sizes: Vec<usize>,
+    /// The first cell of each component, in row-major order.
+    pub starts: Vec<Position>,
+}
+
+impl Components {
+    pub fn len(&self) -> usize {
+        return self.sizes.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.sizes.is_empty();
+    }
+
+    /// The cells of a component, row by row.
+    pub fn cells(&self, label: usize) -> impl Iterator<Item = Position> + '_ {
+        return self
+            .labels
+            .enumerate()
+            .filter(move |(_, l)| **l == label)
+            .map(|(position, _)| position);
+    }
+
+    pub fn area(&self, label: usize) -> usize {
+        return self.sizes[label];
+    }
+
+    /// Number of cell sides between a component and the cells around it, including the edge of the grid.
+    pub fn perimeter(&self, label: usize) -> usize {
+        let grid = &self.labels;
+        return self
+            .cells(label)
+            .map(|position| {
+                let inside = position
+                    .neighbours(grid.num_rows, grid.num_cols)
+                    .filter(|neighbour| grid[neighbour] == label)
+                    .count();
+                4 - inside
+            })
+            .sum();
+    }
+}
+
+/// Label every cell with its connected component, where neighbouring cells belong together if `connected` holds for their values.
+pub fn components<G: GridView>(
+    grid: &G,
+    neighborhood: Neighborhood,
+    connected: impl Fn(&G::Value, &G::Value) -> bool,
+) -> Components {
+    let (num_rows, num_cols) = (grid.num_rows(), grid.num_cols());
+    let mut labels: Grid<Option<usize>> = Grid::filled(num_rows, num_cols, None);
+    let mut sizes = vec![];
+    let mut starts = vec![];
+    let mut stack = vec![];
+
+    for start in labels.iter_positions() {
+        if labels[&start].is_some() {
+            continue;
+        }
+
+        let label = sizes.len();
+        labels[&start] = Some(label);
+        stack.push(start);
+        let mut size = 0;
+
+        while let Some(position) = stack.pop() {
+            size += 1;
+            let value = grid.value(&position);
+            for neighbour in position.neighbours_with(num_rows, num_cols, neighborhood) {
+                if labels[&neighbour].is_none() && connected(&value, &grid.value(&neighbour)) {
+                    labels[&neighbour] = Some(label);
+                    stack.push(neighbour);
+                }
+            }
+        }
+
+        sizes.push(size);
+        starts.push(start);
+    }
+
+    return Components {
+        labels: Grid::from_fn(num_rows, num_cols, |p| labels[&p].unwrap()),
+        sizes,
+        starts,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<char> {
+        return "AAAA\nBBCD\nBBCC\nEEEC".parse().unwrap();
+    }
+
+    #[test]
+    fn test_distances() {
+        let grid: Grid<char> = "..#\n#..\n...".parse().unwrap();
+        let distances = distances(
+            &grid,
+            &[Position::new(0, 0)],
+            Neighborhood::Orthogonal,
+            |_, to| *to == '.',
+        );
+        assert_eq!(distances[&Position::new(2, 0)], Some(4));
+        assert_eq!(distances[&Position::new(0, 2)], None);
+
+        let sources = [Position::new(0, 0), Position::new(2, 2)];
+        let distances = super::distances(&grid, &sources, Neighborhood::Moore, |_, _| true);
+        assert_eq!(distances[&Position::new(1, 1)], Some(1));
+        assert_eq!(distances.iter().max(), Some(&Some(2)));
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        let grid = example();
+        let region = flood_fill(&grid, Position::new(1, 2), Neighborhood::Orthogonal, |c| {
+            *c == 'C'
+        });
+        assert_eq!(region.len(), 4);
+        assert_eq!(perimeter(&region), 10);
+        assert_eq!(
+            flood_fill(&grid, Position::new(0, 0), Neighborhood::Orthogonal, |c| {
+                *c == 'B'
+            }),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn test_components() {
+        let components = components(&example(), Neighborhood::Orthogonal, |a, b| a == b);
+        assert_eq!(components.sizes, vec![4, 4, 4, 1, 3]);
+        assert_eq!(components.starts[3], Position::new(1, 3));
+
+        let c = components.labels[&Position::new(1, 2)];
+        assert_eq!((components.area(c), components.perimeter(c)), (4, 10));
+        assert_eq!(components.perimeter(0), 10);
+        assert_eq!(components.cells(1).count(), 4);
+    }
+}
@@ -1,8 +1,14 @@
 pub mod alu;
+pub mod automaton;
 pub mod cube;
 pub mod geo;
 pub mod grid;
+pub mod grid_algorithms;
+pub mod ocr;
 pub mod packet;
 pub mod scanner;
 pub mod search;
 pub mod snailfish;
+pub mod sparse;
+pub mod tiled;
+pub mod trace;
@@ -0,0 +1,125 @@
+//! Recognition of the block letters some puzzles print as their answer, e.g. day 13.
+//!
+//! Only the small 4x6 font is known. Letters are separated by at least one column of unlit cells.
+
+/// The 4x6 font, glyphs trimmed of surrounding unlit columns.
+const FONT: [(char, &str); 18] = [
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', "###\n.#.\n.#.\n.#.\n.#.\n###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+/// Recognize the letters in a rendered answer, where `#` marks a lit cell.
+/// Returns `None` if the text is not made of known glyphs.
+pub fn recognize(text: &str) -> Option<String> {
+    let cells: Vec<Vec<bool>> = text
+        .lines()
+        .map(|line| line.chars().map(|c| c == '#').collect())
+        .collect();
+    return recognize_grid(&cells);
+}
+
+/// Recognize the letters in a grid of lit cells, indexed by row and column.
+pub fn recognize_grid(cells: &[Vec<bool>]) -> Option<String> {
+    // drop unlit rows above and below the letters.
+    let first = cells.iter().position(|row| row.contains(&true))?;
+    let last = cells.iter().rposition(|row| row.contains(&true))?;
+    let rows = &cells[first..=last];
+    if rows.len() != 6 {
+        return None;
+    }
+
+    let width = rows.iter().map(Vec::len).max()?;
+    let is_lit = |row: &Vec<bool>, col: usize| row.get(col).copied().unwrap_or(false);
+    let is_column_lit = |col: usize| rows.iter().any(|row| is_lit(row, col));
+
+    let mut letters = String::new();
+    let mut col = 0;
+    while col < width {
+        if !is_column_lit(col) {
+            col += 1;
+            continue;
+        }
+
+        let start = col;
+        while col < width && is_column_lit(col) {
+            col += 1;
+        }
+
+        let glyph = rows
+            .iter()
+            .map(|row| {
+                (start..col)
+                    .map(|c| if is_lit(row, c) { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let (letter, _) = FONT.iter().find(|(_, pattern)| *pattern == glyph)?;
+        letters.push(*letter);
+    }
+
+    return Some(letters);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Lay out glyphs of the font next to each other, separated by `gap` unlit columns.
+    fn render(text: &str, gap: usize) -> String {
+        let glyphs: Vec<Vec<&str>> = text
+            .chars()
+            .map(|c| {
+                FONT.iter()
+                    .find(|(l, _)| *l == c)
+                    .unwrap()
+                    .1
+                    .lines()
+                    .collect()
+            })
+            .collect();
+        return (0..6)
+            .map(|row| {
+                glyphs
+                    .iter()
+                    .map(|g| g[row])
+                    .collect::<Vec<_>>()
+                    .join(&".".repeat(gap))
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+    }
+
+    #[test]
+    fn test_recognize() {
+        assert_eq!(recognize(&render("HELLO", 1)), Some("HELLO".into()));
+        assert_eq!(
+            recognize(&format!("\n{}\n\n", render("EFJ", 2))),
+            Some("EFJ".into())
+        );
+    }
+
+    #[test]
+    fn test_unknown_glyphs() {
+        assert_eq!(recognize("#.#\n.#.\n#.#"), None);
+        assert_eq!(recognize("####\n####\n####\n####\n####\n####"), None);
+        assert_eq!(recognize("no letters here"), None);
+    }
+}
@@ -42,7 +42,7 @@ impl Scanner {
         remaining.extend(scanners);
 
         let mut result = Vec::<Scanner>::new();
-        println!("found={:?}", self);
+        crate::aoc_debug!("found={:?}", self);
         result.push(self.clone());
 
         let mut edges = Vec::<Scanner>::new();
@@ -57,7 +57,7 @@ impl Scanner {
                 for existing in &edges {
                     let rotations = rotations.get(&next.id).unwrap();
                     if let Some(relative) = existing.locate_relative_with_rotations(rotations) {
-                        println!("found={:?}", relative);
+                        crate::aoc_debug!("found={:?}", relative);
                         newly_located.push(relative.clone());
                         result.push(relative);
                         found = true;
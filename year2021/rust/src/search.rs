@@ -1,34 +1,419 @@
+use std::borrow::Borrow;
 use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+use std::ops::Add;
 
-#[derive(PartialEq, Eq)]
-pub struct SearchNode<T> {
+use crate::grid::{Grid, Position};
+
+/// The cost of a path, any integer type works.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<C: Copy + Ord + Add<Output = C> + Default> Cost for C {}
+
+/// A state in the search, with the cost to reach it and the estimated cost to reach the goal from it.
+#[derive(Debug, PartialEq, Eq)]
+pub struct SearchNode<T, C = usize> {
     pub value: T,
-    pub dist_start: usize,
-    pub dist_end: usize,
+    pub dist_start: C,
+    pub dist_end: C,
+}
+
+impl<T, C: Cost> SearchNode<T, C> {
+    pub fn new(value: T, dist_start: C, dist_end: C) -> Self {
+        return SearchNode {
+            value,
+            dist_start,
+            dist_end,
+        };
+    }
+
+    /// The estimated cost of a path through this node.
+    pub fn estimate(&self) -> C {
+        return self.dist_start + self.dist_end;
+    }
 }
 
-impl<T: Eq + PartialEq> Ord for SearchNode<T> {
+impl<T: Eq, C: Cost> Ord for SearchNode<T, C> {
     fn cmp(&self, other: &Self) -> Ordering {
-        (other.dist_start + other.dist_end).cmp(&(self.dist_start + other.dist_end))
+        // Reversed to be a min-heap, preferring nodes closer to the goal on ties
+        return other
+            .estimate()
+            .cmp(&self.estimate())
+            .then_with(|| other.dist_end.cmp(&self.dist_end));
     }
 }
 
-impl<T: PartialEq> PartialOrd for SearchNode<T> {
+impl<T: Eq, C: Cost> PartialOrd for SearchNode<T, C> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some((other.dist_start + other.dist_end).cmp(&(self.dist_start + other.dist_end)))
+        return Some(self.cmp(other));
     }
 }
 
-impl<T> SearchNode<T> {
-    pub fn new(value: T, dist_start: usize, dist_end: usize) -> Self {
-        return SearchNode {
-            value,
-            dist_start,
-            dist_end,
-        };
+pub trait HasNeighbors<C: Cost = usize>: Sized {
+    /// The states reachable in one step, with the cost of that step.
+    /// Returning an iterator lets grid-like states step without allocating.
+    fn neighbors(&self) -> impl Iterator<Item = (Self, C)>;
+
+    /// A lower bound of the cost from this state to the goal, used by every search but [`dijkstra`].
+    /// It should never decrease by more than the cost of a step, or the result may not be the cheapest.
+    fn heuristic(&self) -> C {
+        return C::default();
     }
 }
 
-pub trait HasNeighbors<T = Self> {
-    fn neighbors(&self) -> Vec<SearchNode<T>>;
+/// Where [`astar_with`] keeps the cheapest known cost of every state it reached.
+pub trait CostMap<T, C> {
+    fn get(&self, state: &T) -> Option<C>;
+    fn insert(&mut self, state: &T, cost: C);
+}
+
+impl<T: Hash + Eq + Clone, C: Copy> CostMap<T, C> for HashMap<T, C> {
+    fn get(&self, state: &T) -> Option<C> {
+        return HashMap::get(self, state).copied();
+    }
+
+    fn insert(&mut self, state: &T, cost: C) {
+        HashMap::insert(self, state.clone(), cost);
+    }
+}
+
+/// A dense map for states that are positions on a grid, or borrow as one, avoiding hashing in hot searches.
+impl<T: Borrow<Position>, C: Copy> CostMap<T, C> for Grid<Option<C>> {
+    fn get(&self, state: &T) -> Option<C> {
+        return self[state.borrow()];
+    }
+
+    fn insert(&mut self, state: &T, cost: C) {
+        self[state.borrow()] = Some(cost);
+    }
+}
+
+/// The cheapest path from `start` to a state for which `is_goal` holds, guided by [`HasNeighbors::heuristic`].
+/// Returns the goal node, with the total cost of the path in `dist_start`.
+pub fn astar<T, C>(start: T, is_goal: impl Fn(&T) -> bool) -> Option<SearchNode<T, C>>
+where
+    T: HasNeighbors<C> + Hash + Eq + Clone,
+    C: Cost,
+{
+    return search(start, is_goal, T::heuristic, HashMap::new());
+}
+
+/// [`astar`], keeping the costs in `best`, e.g. a `Grid<Option<C>>` the size of the map for positions.
+pub fn astar_with<T, C>(
+    start: T,
+    is_goal: impl Fn(&T) -> bool,
+    best: impl CostMap<T, C>,
+) -> Option<SearchNode<T, C>>
+where
+    T: HasNeighbors<C> + Eq,
+    C: Cost,
+{
+    return search(start, is_goal, T::heuristic, best);
+}
+
+/// The cheapest path from `start` to a state for which `is_goal` holds, ignoring the heuristic.
+/// Returns the goal node, with the total cost of the path in `dist_start`.
+pub fn dijkstra<T, C>(start: T, is_goal: impl Fn(&T) -> bool) -> Option<SearchNode<T, C>>
+where
+    T: HasNeighbors<C> + Hash + Eq + Clone,
+    C: Cost,
+{
+    return search(start, is_goal, |_| C::default(), HashMap::new());
+}
+
+fn search<T, C>(
+    start: T,
+    is_goal: impl Fn(&T) -> bool,
+    heuristic: impl Fn(&T) -> C,
+    mut best: impl CostMap<T, C>,
+) -> Option<SearchNode<T, C>>
+where
+    T: HasNeighbors<C> + Eq,
+    C: Cost,
+{
+    // A node popped at a higher cost than the best known was already expanded more cheaply,
+    // so `best` doubles as the closed set.
+    let mut open = BinaryHeap::<SearchNode<T, C>>::new();
+
+    best.insert(&start, C::default());
+    let dist_end = heuristic(&start);
+    open.push(SearchNode::new(start, C::default(), dist_end));
+
+    while let Some(node) = open.pop() {
+        if best
+            .get(&node.value)
+            .is_some_and(|known| known < node.dist_start)
+        {
+            continue;
+        }
+        if is_goal(&node.value) {
+            return Some(node);
+        }
+
+        for (neighbor, cost) in node.value.neighbors() {
+            let dist_start = node.dist_start + cost;
+            if best.get(&neighbor).is_none_or(|known| dist_start < known) {
+                best.insert(&neighbor, dist_start);
+                let dist_end = heuristic(&neighbor);
+                open.push(SearchNode::new(neighbor, dist_start, dist_end));
+            }
+        }
+    }
+
+    return None;
+}
+
+/// A node on the path of [`ida_star`], with the neighbours still to visit.
+type Frame<T, C> = (SearchNode<T, C>, std::vec::IntoIter<(T, C)>);
+
+/// The cheapest path from `start` to a goal by iterative deepening: depth-first under a bound on the estimated cost,
+/// raising the bound until a goal is found. Only the current path is kept in memory, at the price of expanding states
+/// again for every bound. Returns the goal node, with the total cost of the path in `dist_start`.
+pub fn ida_star<T, C>(start: T, is_goal: impl Fn(&T) -> bool) -> Option<SearchNode<T, C>>
+where
+    T: HasNeighbors<C> + Hash + Eq + Clone,
+    C: Cost,
+{
+    let dist_end = start.heuristic();
+    let start = SearchNode::new(start, C::default(), dist_end);
+    let mut bound = Some(start.estimate());
+
+    while let Some(limit) = bound {
+        // the lowest estimate over the limit, the bound of the next round.
+        bound = None;
+        let mut stack: Vec<Frame<T, C>> = vec![];
+        let mut on_path = HashSet::<T>::new();
+        let mut candidate = Some(SearchNode::new(
+            start.value.clone(),
+            start.dist_start,
+            start.dist_end,
+        ));
+
+        loop {
+            if let Some(node) = candidate.take() {
+                let estimate = node.estimate();
+                if estimate > limit {
+                    bound = Some(bound.map_or(estimate, |bound: C| bound.min(estimate)));
+                } else if is_goal(&node.value) {
+                    return Some(node);
+                } else if on_path.insert(node.value.clone()) {
+                    let neighbors = node.value.neighbors().collect::<Vec<_>>().into_iter();
+                    stack.push((node, neighbors));
+                }
+            }
+
+            match stack.last_mut() {
+                None => break,
+                Some((node, neighbors)) => match neighbors.next() {
+                    Some((neighbor, cost)) => {
+                        if !on_path.contains(&neighbor) {
+                            let dist_end = neighbor.heuristic();
+                            candidate =
+                                Some(SearchNode::new(neighbor, node.dist_start + cost, dist_end));
+                        }
+                    }
+                    None => {
+                        let (node, _) = stack.pop().unwrap();
+                        on_path.remove(&node.value);
+                    }
+                },
+            }
+        }
+    }
+
+    return None;
+}
+
+/// A path from `start` to a goal, searching layer by layer and keeping only the `width` states with the lowest
+/// estimated cost in every layer. Memory is bounded by the width, but a beam that is too narrow can miss the cheapest
+/// path, or every path. States are not remembered between layers, so this may not end if no goal is reachable.
+pub fn beam<T, C>(start: T, is_goal: impl Fn(&T) -> bool, width: usize) -> Option<SearchNode<T, C>>
+where
+    T: HasNeighbors<C> + Hash + Eq + Clone,
+    C: Cost,
+{
+    let dist_end = start.heuristic();
+    let mut layer = vec![SearchNode::new(start, C::default(), dist_end)];
+    let mut best: Option<SearchNode<T, C>> = None;
+
+    while !layer.is_empty() {
+        // the cheapest node for every state in the next layer, in the order they were found.
+        let mut next: Vec<SearchNode<T, C>> = vec![];
+        let mut indices = HashMap::<T, usize>::new();
+
+        for node in layer {
+            if is_goal(&node.value) {
+                if best
+                    .as_ref()
+                    .is_none_or(|best| node.dist_start < best.dist_start)
+                {
+                    best = Some(node);
+                }
+                continue;
+            }
+
+            for (neighbor, cost) in node.value.neighbors() {
+                let dist_start = node.dist_start + cost;
+                match indices.get(&neighbor) {
+                    Some(&index) if next[index].dist_start <= dist_start => {}
+                    Some(&index) => next[index].dist_start = dist_start,
+                    None => {
+                        indices.insert(neighbor.clone(), next.len());
+                        let dist_end = neighbor.heuristic();
+                        next.push(SearchNode::new(neighbor, dist_start, dist_end));
+                    }
+                }
+            }
+        }
+
+        if best.is_some() {
+            // goals in later layers may still be cheaper, but the beam would have to keep going to know.
+            return best;
+        }
+
+        next.sort_by_key(|node| node.estimate());
+        next.truncate(width);
+        layer = next;
+    }
+
+    return None;
+}
+
+/// How [`find`] explores the states, see the search functions of the same name.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Strategy {
+    AStar,
+    Dijkstra,
+    IdaStar,
+    Beam(usize),
+}
+
+/// A path from `start` to a goal using `strategy`, so a day can switch strategy without changing anything else.
+pub fn find<T, C>(
+    strategy: Strategy,
+    start: T,
+    is_goal: impl Fn(&T) -> bool,
+) -> Option<SearchNode<T, C>>
+where
+    T: HasNeighbors<C> + Hash + Eq + Clone,
+    C: Cost,
+{
+    return match strategy {
+        Strategy::AStar => astar(start, is_goal),
+        Strategy::Dijkstra => dijkstra(start, is_goal),
+        Strategy::IdaStar => ida_star(start, is_goal),
+        Strategy::Beam(width) => beam(start, is_goal, width),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A number line where stepping left costs 1 and right costs 3, with a shortcut from 0 to 10.
+    #[derive(Debug, Clone, Hash, Eq, PartialEq)]
+    struct Line(i64);
+
+    impl HasNeighbors<u8> for Line {
+        fn neighbors(&self) -> impl Iterator<Item = (Self, u8)> {
+            let shortcut = (self.0 == 0).then_some((Line(10), 5));
+            return [(Line(self.0 - 1), 1), (Line(self.0 + 1), 3)]
+                .into_iter()
+                .chain(shortcut);
+        }
+
+        fn heuristic(&self) -> u8 {
+            return ((12 - self.0) / 2).clamp(0, 6) as u8;
+        }
+    }
+
+    #[test]
+    fn test_ordering() {
+        let mut heap = BinaryHeap::new();
+        heap.push(SearchNode::new('a', 5, 1));
+        heap.push(SearchNode::new('b', 1, 2));
+        heap.push(SearchNode::new('c', 2, 1));
+        heap.push(SearchNode::new('d', 0, 9));
+        let order: Vec<char> = std::iter::from_fn(|| heap.pop().map(|n| n.value)).collect();
+        assert_eq!(order, vec!['c', 'b', 'a', 'd']);
+    }
+
+    #[test]
+    fn test_astar() {
+        // 0 -> 10 costs 5, then two steps of 3 reach 12
+        let goal = astar(Line(0), |line| line.0 == 12).unwrap();
+        assert_eq!((goal.value, goal.dist_start), (Line(12), 11));
+
+        let goal = dijkstra(Line(0), |line| line.0 == 12).unwrap();
+        assert_eq!(goal.dist_start, 11);
+    }
+
+    #[test]
+    fn test_astar_with_grid() {
+        /// A position on a 3x3 grid where every step costs the row it enters plus one.
+        #[derive(PartialEq, Eq)]
+        struct Cell(Position);
+
+        impl Borrow<Position> for Cell {
+            fn borrow(&self) -> &Position {
+                return &self.0;
+            }
+        }
+
+        impl HasNeighbors for Cell {
+            fn neighbors(&self) -> impl Iterator<Item = (Self, usize)> {
+                return self.0.neighbours(3, 3).map(|p| (Cell(p), p.row + 1));
+            }
+        }
+
+        let best = Grid::filled(3, 3, None);
+        let goal = astar_with(
+            Cell(Position::new(0, 0)),
+            |c| c.0 == Position::new(2, 2),
+            best,
+        );
+        // along the top row, then down
+        assert_eq!(goal.unwrap().dist_start, 1 + 1 + 2 + 3);
+    }
+
+    #[test]
+    fn test_strategies() {
+        let strategies = [
+            Strategy::AStar,
+            Strategy::Dijkstra,
+            Strategy::IdaStar,
+            Strategy::Beam(10),
+        ];
+        for strategy in strategies {
+            let goal = find(strategy, Line(0), |line| line.0 == 12).unwrap();
+            assert_eq!(
+                (goal.value, goal.dist_start),
+                (Line(12), 11),
+                "{:?}",
+                strategy
+            );
+        }
+    }
+
+    #[test]
+    fn test_wide_costs() {
+        /// Doubling up to 64, where every step costs more than fits in a `u64`.
+        #[derive(Clone, Hash, Eq, PartialEq)]
+        struct Double(u64);
+
+        impl HasNeighbors<u128> for Double {
+            fn neighbors(&self) -> impl Iterator<Item = (Self, u128)> {
+                return (self.0 < 64)
+                    .then_some((Double(self.0 * 2), u64::MAX as u128 + 1))
+                    .into_iter();
+            }
+        }
+
+        let goal = dijkstra(Double(1), |d| d.0 == 8).unwrap();
+        assert_eq!(goal.dist_start, 3 * (u64::MAX as u128 + 1));
+        assert!(dijkstra(Double(1), |d| d.0 == 3).is_none());
+        assert!(ida_star(Double(1), |d| d.0 == 3).is_none());
+        assert!(beam(Double(1), |d| d.0 == 3, 1).is_none());
+    }
 }
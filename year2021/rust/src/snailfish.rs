@@ -88,14 +88,14 @@ impl<'a, 'b> Add<&'b Node> for &'a Node {
 
     fn add(self, other: &'b Node) -> Node {
         let mut node = Node::pair(self.clone(), other.clone());
-        // println!("after addition: {}", node);
+        crate::aoc_trace!("after addition: {}", node);
         loop {
             if let Some(n) = node.explode() {
                 node = n;
-                // println!("after explode:  {}", node);
+                crate::aoc_trace!("after explode:  {}", node);
             } else if let Some(n) = node.split() {
                 node = n;
-                // println!("after split:    {}", node);
+                crate::aoc_trace!("after split:    {}", node);
             } else {
                 break;
             }
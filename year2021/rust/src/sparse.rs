@@ -0,0 +1,284 @@
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, Index, IndexMut};
+
+use crate::grid::{Grid, Neighborhood, Position};
+
+/// A position with signed coordinates, so it can lie anywhere around the origin.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Point {
+    pub row: isize,
+    pub col: isize,
+}
+
+impl Point {
+    pub fn new(row: isize, col: isize) -> Self {
+        Point { row, col }
+    }
+
+    /// The neighbours of this point in `neighborhood`, without any bounds.
+    pub fn neighbours(&self, neighborhood: Neighborhood) -> impl Iterator<Item = Point> {
+        let point = *self;
+        return neighborhood
+            .offsets()
+            .iter()
+            .map(move |(dr, dc)| Point::new(point.row + dr, point.col + dc));
+    }
+}
+
+impl From<Position> for Point {
+    fn from(position: Position) -> Self {
+        return Point::new(position.row as isize, position.col as isize);
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Self::Output {
+        return Point::new(self.row + other.row, self.col + other.col);
+    }
+}
+
+/// The smallest rectangle containing a set of points, both corners inclusive.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub struct Bounds {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Bounds {
+    pub fn contains(&self, point: &Point) -> bool {
+        return (self.min.row..=self.max.row).contains(&point.row)
+            && (self.min.col..=self.max.col).contains(&point.col);
+    }
+
+    pub fn num_rows(&self) -> usize {
+        return (self.max.row - self.min.row + 1) as usize;
+    }
+
+    pub fn num_cols(&self) -> usize {
+        return (self.max.col - self.min.col + 1) as usize;
+    }
+
+    /// Grow the bounds by `margin` cells on every side.
+    pub fn expand(&self, margin: isize) -> Self {
+        return Bounds {
+            min: Point::new(self.min.row - margin, self.min.col - margin),
+            max: Point::new(self.max.row + margin, self.max.col + margin),
+        };
+    }
+
+    /// All points within the bounds, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let (min, max) = (self.min, self.max);
+        return (min.row..=max.row)
+            .flat_map(move |row| (min.col..=max.col).map(move |col| Point::new(row, col)));
+    }
+
+    fn include(&mut self, point: &Point) {
+        self.min = Point::new(self.min.row.min(point.row), self.min.col.min(point.col));
+        self.max = Point::new(self.max.row.max(point.row), self.max.col.max(point.col));
+    }
+}
+
+/// An unbounded grid that only stores the cells that were set, all other cells have the default value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    values: HashMap<Point, T>,
+    default: T,
+    bounds: Option<Bounds>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new(default: T) -> Self {
+        return SparseGrid {
+            values: HashMap::new(),
+            default,
+            bounds: None,
+        };
+    }
+
+    /// Copy the cells of `grid` that differ from `default`, with the top left cell at `offset`.
+    pub fn from_grid(grid: &Grid<T>, offset: Point, default: T) -> Self
+    where
+        T: Clone + PartialEq,
+    {
+        let mut sparse = SparseGrid::new(default);
+        for (position, value) in grid.enumerate() {
+            if *value != sparse.default {
+                let point = Point::from(position) + offset;
+                sparse.insert(point, value.clone());
+            }
+        }
+        return sparse;
+    }
+
+    pub fn default_value(&self) -> &T {
+        return &self.default;
+    }
+
+    /// The value of a cell, or the default value if it was never set.
+    pub fn get(&self, point: &Point) -> &T {
+        return self.values.get(point).unwrap_or(&self.default);
+    }
+
+    /// Whether the cell was set, even if it was set to the default value.
+    pub fn contains(&self, point: &Point) -> bool {
+        return self.values.contains_key(point);
+    }
+
+    pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        match &mut self.bounds {
+            Some(bounds) => bounds.include(&point),
+            None => {
+                self.bounds = Some(Bounds {
+                    min: point,
+                    max: point,
+                })
+            }
+        }
+        return self.values.insert(point, value);
+    }
+
+    pub fn remove(&mut self, point: &Point) -> Option<T> {
+        let value = self.values.remove(point)?;
+        let on_edge = self.bounds.is_some_and(|bounds| {
+            point.row == bounds.min.row
+                || point.row == bounds.max.row
+                || point.col == bounds.min.col
+                || point.col == bounds.max.col
+        });
+        if on_edge {
+            self.bounds = self.compute_bounds();
+        }
+        return Some(value);
+    }
+
+    /// Number of cells that were set.
+    pub fn len(&self) -> usize {
+        return self.values.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.values.is_empty();
+    }
+
+    /// The cells that were set, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&Point, &T)> + '_ {
+        return self.values.iter();
+    }
+
+    /// The smallest rectangle containing all cells that were set, `None` if there are none.
+    pub fn bounds(&self) -> Option<Bounds> {
+        return self.bounds;
+    }
+
+    /// Render the populated region with one line per row, mapping every cell to a character with `f`.
+    pub fn render(&self, f: impl Fn(&T) -> char) -> String {
+        let bounds = match self.bounds {
+            Some(bounds) => bounds,
+            None => return String::new(),
+        };
+        return (bounds.min.row..=bounds.max.row)
+            .map(|row| {
+                (bounds.min.col..=bounds.max.col)
+                    .map(|col| f(self.get(&Point::new(row, col))))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+    }
+
+    /// Copy the populated region into a dense grid, returned with the point of its top left cell.
+    pub fn to_grid(&self) -> Option<(Grid<T>, Point)>
+    where
+        T: Clone,
+    {
+        let bounds = self.bounds?;
+        let grid = Grid::from_fn(bounds.num_rows(), bounds.num_cols(), |p| {
+            let point = Point::from(p) + bounds.min;
+            self.get(&point).clone()
+        });
+        return Some((grid, bounds.min));
+    }
+
+    fn compute_bounds(&self) -> Option<Bounds> {
+        let mut points = self.values.keys();
+        let first = *points.next()?;
+        let mut bounds = Bounds {
+            min: first,
+            max: first,
+        };
+        points.for_each(|point| bounds.include(point));
+        return Some(bounds);
+    }
+}
+
+impl<T: Hash> Hash for SparseGrid<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // the order of a HashMap is arbitrary, so hash the cells in order.
+        let mut values: Vec<_> = self.values.iter().collect();
+        values.sort_unstable_by_key(|(point, _)| **point);
+        values.hash(state);
+        self.default.hash(state);
+    }
+}
+
+impl<T> Index<&Point> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, point: &Point) -> &Self::Output {
+        return self.get(point);
+    }
+}
+
+/// Mutable access to a cell, setting it to the default value first if needed.
+impl<T: Clone> IndexMut<&Point> for SparseGrid<T> {
+    fn index_mut(&mut self, point: &Point) -> &mut Self::Output {
+        if !self.contains(point) {
+            self.insert(*point, self.default.clone());
+        }
+        return self.values.get_mut(point).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_and_bounds() {
+        let mut grid = SparseGrid::new('.');
+        assert_eq!(grid.bounds(), None);
+        assert_eq!(grid.render(|c| *c), "");
+
+        grid.insert(Point::new(-1, 2), '#');
+        grid[&Point::new(1, -1)] = 'o';
+        assert_eq!(grid[&Point::new(0, 0)], '.');
+        assert_eq!(grid.len(), 2);
+        assert_eq!(
+            grid.bounds(),
+            Some(Bounds {
+                min: Point::new(-1, -1),
+                max: Point::new(1, 2)
+            })
+        );
+        assert_eq!(grid.render(|c| *c), "...#\n....\no...");
+
+        grid.remove(&Point::new(-1, 2));
+        assert_eq!(grid.render(|c| *c), "o");
+    }
+
+    #[test]
+    fn test_dense_conversion() {
+        let dense: Grid<char> = ".#\n#.".parse().unwrap();
+        let sparse = SparseGrid::from_grid(&dense, Point::new(-5, 3), '.');
+        assert_eq!(sparse.len(), 2);
+        assert_eq!(sparse[&Point::new(-5, 4)], '#');
+
+        let (grid, offset) = sparse.to_grid().unwrap();
+        assert_eq!(offset, Point::new(-5, 3));
+        assert_eq!(grid, dense);
+    }
+}
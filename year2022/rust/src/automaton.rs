@@ -0,0 +1,264 @@
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+
+use crate::grid::{Grid, Position};
+use crate::sparse::{Point, SparseGrid};
+
+/// The state of a step-based simulation.
+pub trait Automaton {
+    /// The state after one step.
+    fn step(&self) -> Self;
+}
+
+/// Run `steps` steps.
+pub fn run<A: Automaton>(start: A, steps: usize) -> A {
+    let mut state = start;
+    for _ in 0..steps {
+        state = state.step();
+    }
+    return state;
+}
+
+/// Step until `done(previous, next)` holds, returns the last state and the number of steps taken.
+pub fn run_until<A: Automaton>(start: A, mut done: impl FnMut(&A, &A) -> bool) -> (A, usize) {
+    let mut state = start;
+    let mut steps = 0;
+    loop {
+        let next = state.step();
+        steps += 1;
+        if done(&state, &next) {
+            return (next, steps);
+        }
+        state = next;
+    }
+}
+
+/// Step until a step no longer changes the state, returns it and the number of steps taken including that last step.
+pub fn run_until_stable<A: Automaton + PartialEq>(start: A) -> (A, usize) {
+    return run_until(start, |previous, next| previous == next);
+}
+
+/// States repeat from step `start` on, every `length` steps.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+/// Step until a state repeats. This does not end for simulations that never repeat.
+pub fn find_cycle<A: Automaton + Clone + Eq + Hash>(start: A) -> Cycle {
+    return detect(start, usize::MAX).1.unwrap();
+}
+
+/// Run `steps` steps, jumping ahead as soon as the states start repeating.
+pub fn fast_forward<A: Automaton + Clone + Eq + Hash>(start: A, steps: usize) -> A {
+    return detect(start, steps).0;
+}
+
+/// Step up to `steps` times, remembering every state, until a state repeats.
+fn detect<A: Automaton + Clone + Eq + Hash>(start: A, steps: usize) -> (A, Option<Cycle>) {
+    let mut seen: HashMap<A, usize> = HashMap::new();
+    let mut states: Vec<A> = vec![];
+    let mut state = start;
+
+    for step in 0..steps {
+        if let Some(first) = seen.get(&state) {
+            let cycle = Cycle { start: *first, length: step - first };
+            let index = cycle.start + (steps - cycle.start) % cycle.length;
+            return (states.swap_remove(index), Some(cycle));
+        }
+        let next = state.step();
+        seen.insert(state.clone(), step);
+        states.push(state);
+        state = next;
+    }
+
+    return (state, None);
+}
+
+type DenseRule<T> = Rc<dyn Fn(&Grid<T>, &Position) -> T>;
+
+/// A grid where every step computes all cells from the previous generation at once, in one or more phases.
+#[derive(Clone)]
+pub struct GridAutomaton<T> {
+    pub grid: Grid<T>,
+    phases: Vec<DenseRule<T>>,
+}
+
+impl<T> GridAutomaton<T> {
+    /// Simulate `grid` where `rule` gives the next value of a cell.
+    pub fn new(grid: Grid<T>, rule: impl Fn(&Grid<T>, &Position) -> T + 'static) -> Self {
+        return GridAutomaton { grid, phases: vec![Rc::new(rule)] };
+    }
+
+    /// Add a phase to every step, which applies `rule` to the result of the previous phase.
+    pub fn then(mut self, rule: impl Fn(&Grid<T>, &Position) -> T + 'static) -> Self {
+        self.phases.push(Rc::new(rule));
+        return self;
+    }
+}
+
+impl<T> Automaton for GridAutomaton<T> {
+    fn step(&self) -> Self {
+        let mut phases = self.phases.iter();
+        let rule = phases.next().unwrap();
+        let mut grid = Grid::from_fn(self.grid.num_rows, self.grid.num_cols, |p| rule(&self.grid, &p));
+        for rule in phases {
+            grid = Grid::from_fn(grid.num_rows, grid.num_cols, |p| rule(&grid, &p));
+        }
+        return GridAutomaton { grid, phases: self.phases.clone() };
+    }
+}
+
+impl<T: PartialEq> PartialEq for GridAutomaton<T> {
+    fn eq(&self, other: &Self) -> bool {
+        return self.grid == other.grid;
+    }
+}
+
+impl<T: Eq> Eq for GridAutomaton<T> {}
+
+impl<T: Hash> Hash for GridAutomaton<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.grid.hash(state);
+    }
+}
+
+type SparseRule<T> = Rc<dyn Fn(&SparseGrid<T>, &Point) -> T>;
+
+/// An unbounded grid where every step computes all cells from the previous generation at once, in one or more phases.
+/// Rules may only look at the eight cells around a cell, so cells far from any set cell all get the same value.
+#[derive(Clone)]
+pub struct SparseAutomaton<T> {
+    pub grid: SparseGrid<T>,
+    phases: Vec<SparseRule<T>>,
+}
+
+impl<T> SparseAutomaton<T> {
+    /// Simulate `grid` where `rule` gives the next value of a cell.
+    pub fn new(grid: SparseGrid<T>, rule: impl Fn(&SparseGrid<T>, &Point) -> T + 'static) -> Self {
+        return SparseAutomaton { grid, phases: vec![Rc::new(rule)] };
+    }
+
+    /// Add a phase to every step, which applies `rule` to the result of the previous phase.
+    pub fn then(mut self, rule: impl Fn(&SparseGrid<T>, &Point) -> T + 'static) -> Self {
+        self.phases.push(Rc::new(rule));
+        return self;
+    }
+}
+
+impl<T: PartialEq> SparseAutomaton<T> {
+    fn apply(grid: &SparseGrid<T>, rule: &SparseRule<T>) -> SparseGrid<T> {
+        let bounds = match grid.bounds() {
+            Some(bounds) => bounds,
+            None => return SparseGrid::new(rule(grid, &Point::new(0, 0))),
+        };
+
+        // a cell without any set cell around it, which stands for all of them.
+        let outside = bounds.max + Point::new(2, 2);
+        let mut next = SparseGrid::new(rule(grid, &outside));
+        for point in bounds.expand(1).points() {
+            let value = rule(grid, &point);
+            if value != *next.default_value() {
+                next.insert(point, value);
+            }
+        }
+        return next;
+    }
+}
+
+impl<T: PartialEq> Automaton for SparseAutomaton<T> {
+    fn step(&self) -> Self {
+        let mut phases = self.phases.iter();
+        let mut grid = SparseAutomaton::apply(&self.grid, phases.next().unwrap());
+        for rule in phases {
+            grid = SparseAutomaton::apply(&grid, rule);
+        }
+        return SparseAutomaton { grid, phases: self.phases.clone() };
+    }
+}
+
+impl<T: PartialEq> PartialEq for SparseAutomaton<T> {
+    fn eq(&self, other: &Self) -> bool {
+        return self.grid == other.grid;
+    }
+}
+
+impl<T: Eq> Eq for SparseAutomaton<T> {}
+
+impl<T: Hash> Hash for SparseAutomaton<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.grid.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Neighborhood;
+
+    fn life(alive: bool, neighbors: usize) -> bool {
+        return neighbors == 3 || (alive && neighbors == 2);
+    }
+
+    fn blinker() -> GridAutomaton<bool> {
+        let grid = Grid::parse(".....\n..#..\n..#..\n..#..\n.....", |c| c == '#').unwrap();
+        return GridAutomaton::new(grid, |grid, position| {
+            let neighbors = position.neighbors_with(grid.num_rows, grid.num_cols, Neighborhood::Moore)
+                .filter(|neighbor| grid[neighbor])
+                .count();
+            life(grid[position], neighbors)
+        });
+    }
+
+    #[test]
+    fn test_grid_automaton() {
+        let start = blinker();
+        let next = start.step();
+        assert_eq!(next.grid, Grid::parse(".....\n.....\n.###.\n.....\n.....", |c| c == '#').unwrap());
+        assert!(run(start.clone(), 2) == start);
+        assert_eq!(find_cycle(start.clone()), Cycle { start: 0, length: 2 });
+        assert!(fast_forward(start, 1_000_001) == next);
+    }
+
+    #[test]
+    fn test_phases() {
+        // every step moves the single cell right, then down, wrapping around.
+        let grid = Grid::parse("#..\n...", |c| c).unwrap();
+        let shift = |dr: isize, dc: isize| {
+            move |grid: &Grid<char>, p: &Position| {
+                let row = (p.row as isize - dr).rem_euclid(grid.num_rows as isize) as usize;
+                let col = (p.col as isize - dc).rem_euclid(grid.num_cols as isize) as usize;
+                grid[&Position::new(row, col)]
+            }
+        };
+        let automaton = GridAutomaton::new(grid, shift(0, 1)).then(shift(1, 0));
+        assert_eq!(run(automaton.clone(), 1).grid, Grid::parse("...\n.#.", |c| c).unwrap());
+        assert_eq!(find_cycle(automaton), Cycle { start: 0, length: 6 });
+    }
+
+    #[test]
+    fn test_sparse_automaton() {
+        let glider = Grid::parse(".#.\n..#\n###", |c| c == '#').unwrap();
+        let start = SparseAutomaton::new(SparseGrid::from_grid(&glider, Point::new(0, 0), false), |grid, point| {
+            let neighbors = point.neighbors(Neighborhood::Moore).filter(|neighbor| grid[neighbor]).count();
+            life(grid[point], neighbors)
+        });
+
+        // a glider moves one cell down and right every four steps.
+        let moved = run(start.clone(), 4);
+        assert_eq!(moved.grid.bounds().unwrap().min, start.grid.bounds().unwrap().min + Point::new(1, 1));
+        assert_eq!(moved.grid.render(|v| if *v { '#' } else { '.' }), ".#.\n..#\n###");
+    }
+
+    #[test]
+    fn test_run_until_stable() {
+        // a cell that dies of loneliness.
+        let grid = Grid::parse("...\n.#.\n...", |c| c == '#').unwrap();
+        let automaton = GridAutomaton::new(grid, |_, _| false);
+        let (end, steps) = run_until_stable(automaton);
+        assert_eq!(steps, 2);
+        assert!(end.grid.iter().all(|v| !*v));
+    }
+}
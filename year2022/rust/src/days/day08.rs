@@ -33,114 +33,48 @@ pub fn part2() -> String {
 }
 
 fn count(grid: &Grid<usize>) -> usize {
-    let mut count = 0;
-
-    for r in 0..grid.num_rows {
-        for c in 0..grid.num_cols {
-            if check_visibility(grid, &Position::new(r, c)) {
-                count += 1;
-            }
-        }
-    }
-
-    return count;
+    // columns of the grid are rows of its transpose, contiguous in memory.
+    let transposed = grid.transpose();
+    return grid.iter_positions().filter(|p| check_visibility(grid, &transposed, p)).count();
 }
 
 fn most_scenic(grid: &Grid<usize>) -> usize {
-    let mut scenic = 0;
-
-    for r in 0..grid.num_rows {
-        for c in 0..grid.num_cols {
-            let s = check_scenic(grid, &Position::new(r, c));
-            if s > scenic {
-                scenic = s;
-            }
-        }
-    }
-
-    return scenic;
+    let transposed = grid.transpose();
+    return grid.iter_positions().map(|p| check_scenic(grid, &transposed, &p)).max().unwrap_or(0);
 }
 
-fn check_visibility(grid: &Grid<usize>, p: &Position) -> bool {
-    if p.row == 0 || p.col == 0 || p.row == grid.num_rows - 1 || p.col == grid.num_cols - 1 {
-        return true
-    }
-
+fn check_visibility(grid: &Grid<usize>, transposed: &Grid<usize>, p: &Position) -> bool {
     let height = grid[p];
+    let row = grid.row(p.row);
+    let column = transposed.row(p.col);
 
-    let mut up = true;
-    for r in 0..p.row {
-        if grid[&Position::new(r, p.col)] >= height {
-            up = false
-        }
-    }
-    if up { return true; }
-
-    let mut down = true;
-    for r in p.row + 1..grid.num_rows {
-        if grid[&Position::new(r, p.col)] >= height {
-            down = false;
-        }
-    }
-    if down { return true; }
-
-    let mut left = true;
-    for c in 0..p.col {
-        if grid[&Position::new(p.row, c)] >= height {
-            left = false
-        }
-    }
-    if left { return true; }
-
-    let mut right = true;
-    for c in p.col + 1..grid.num_cols {
-        if grid[&Position::new(p.row, c)] >= height {
-            right = false
-        }
-    }
-    if right { return true; }
-
-    return false;
+    return row[..p.col].iter().all(|h| *h < height)
+        || row[p.col + 1..].iter().all(|h| *h < height)
+        || column[..p.row].iter().all(|h| *h < height)
+        || column[p.row + 1..].iter().all(|h| *h < height);
 }
 
-fn check_scenic(grid: &Grid<usize>, p: &Position) -> usize {
-    if p.row == 0 || p.col == 0 || p.row == grid.num_rows - 1 || p.col == grid.num_cols - 1 {
-        return 0
-    }
-
+fn check_scenic(grid: &Grid<usize>, transposed: &Grid<usize>, p: &Position) -> usize {
     let height = grid[p];
+    let row = grid.row(p.row);
+    let column = transposed.row(p.col);
 
-    let mut up = 0;
-    for r in (0..p.row).rev() {
-        up += 1;
-        if grid[&Position::new(r, p.col)] >= height {
-            break;
-        }
-    }
-
-    let mut down = 0;
-    for r in p.row + 1..grid.num_rows {
-        down += 1;
-        if grid[&Position::new(r, p.col)] >= height {
-            break;
-        }
-    }
+    let up = viewing_distance(column[..p.row].iter().rev(), height);
+    let down = viewing_distance(column[p.row + 1..].iter(), height);
+    let left = viewing_distance(row[..p.col].iter().rev(), height);
+    let right = viewing_distance(row[p.col + 1..].iter(), height);
 
-    let mut left = 0;
-    for c in (0..p.col).rev() {
-        left += 1;
-        if grid[&Position::new(p.row, c)] >= height {
-            break;
-        }
-    }
+    return up * down * left * right;
+}
 
-    let mut right = 0;
-    for c in p.col + 1..grid.num_cols {
-        right += 1;
-        if grid[&Position::new(p.row, c)] >= height {
+/// Number of trees visible in the given line of sight, up to and including the first one that is at least as high.
+fn viewing_distance<'a>(trees: impl Iterator<Item=&'a usize>, height: usize) -> usize {
+    let mut distance = 0;
+    for tree in trees {
+        distance += 1;
+        if *tree >= height {
             break;
         }
     }
-
-    return up * down * left * right;
+    return distance;
 }
@@ -1,8 +1,8 @@
-use std::collections::HashSet;
-
 use recap::Recap;
 use serde::Deserialize;
 
+use year2022::sparse::{Point, SparseGrid};
+
 pub fn part1() -> String {
     let input = include_str!("res/input09.txt").lines().collect::<Vec<_>>();
     let instructions = input.iter().map(|line| { line.parse::<Instruction>().unwrap() }).collect::<Vec<_>>();
@@ -20,7 +20,7 @@ pub fn part2() -> String {
 }
 
 fn simulate_rope(instructions: Vec<Instruction>, knot_count: usize) -> usize {
-    let mut visited = HashSet::<Point>::new();
+    let mut visited = SparseGrid::new(false);
     let mut knots = Vec::<Point>::new();
     for _ in 0..knot_count {
         knots.push(Point::new(0, 0));
@@ -47,7 +47,7 @@ fn simulate_rope(instructions: Vec<Instruction>, knot_count: usize) -> usize {
                 let (first, second) = knots.split_at_mut(i + 1);
                 tail_follow(&first[first.len() - 1], &mut second[0]);
             }
-            visited.insert(knots[knots.len() - 1].clone());
+            visited.insert(knots[knots.len() - 1], true);
         }
     }
 
@@ -61,18 +61,6 @@ struct Instruction {
     pub distance: usize,
 }
 
-#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
-pub struct Point {
-    pub row: isize,
-    pub col: isize,
-}
-
-impl Point {
-    pub fn new(row: isize, col: isize) -> Self {
-        Point { row, col }
-    }
-}
-
 fn tail_follow(head: &Point, tail: &mut Point) {
     if head.row > tail.row {
         if head.row - tail.row > 1 {
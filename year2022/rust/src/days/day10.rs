@@ -12,10 +12,10 @@ pub fn part1() -> String {
     let mut registry: isize = 1;
     for op in operations {
         let mut cycle = || {
-            // println!("cycle: {} registry: {}, op: {:?}", clock, registry, op);
+            year2022::aoc_trace!("cycle: {} registry: {}, op: {:?}", clock, registry, op);
             if (clock + 20) % 40 == 0 {
                 answer += clock * registry;
-                // println!("cycle: {} registry: {}, op: {:?}", clock, registry, op);
+                year2022::aoc_debug!("cycle: {} registry: {}, op: {:?}", clock, registry, op);
             }
             clock += 1;
         };
@@ -41,19 +41,13 @@ pub fn part2() -> String {
         .collect::<Vec<_>>();
 
 
+    let mut screen = vec![vec![false; 40]; 6];
     let mut clock: isize = 1;
     let mut registry: isize = 1;
     for op in operations {
         let mut cycle = || {
             let crt = (clock - 1) % 40;
-            if registry - 1 <= crt && crt <= registry + 1 {
-                print!("#");
-            } else {
-                print!(".");
-            }
-            if clock % 40 == 0 {
-                println!();
-            }
+            screen[((clock - 1) / 40) as usize][crt as usize] = registry - 1 <= crt && crt <= registry + 1;
             clock += 1;
         };
 
@@ -69,7 +63,10 @@ pub fn part2() -> String {
         }
     }
 
-    return format!("{:?}", 0);
+    // fall back to the rendered screen if the letters aren't known.
+    return year2022::ocr::recognize_grid(&screen).unwrap_or_else(|| screen.iter()
+        .map(|row| row.iter().map(|&lit| if lit { '#' } else { '.' }).collect::<String>())
+        .collect::<Vec<_>>().join("\n"));
 }
 
 #[derive(Debug)]
@@ -21,7 +21,7 @@ pub fn part1() -> String {
                 monkeys[new_id].items.push_back(new_item);
             }
         }
-        // println!("round: {}, monkeys: {:?}", _round + 1, monkeys)
+        year2022::aoc_trace!("round: {}, monkeys: {:?}", _round + 1, monkeys);
     }
 
     monkeys.sort_by(|m1, m2| m2.inspection_count.cmp(&m1.inspection_count));
@@ -52,7 +52,7 @@ pub fn part2() -> String {
                 monkeys[new_id].items.push_back(new_item);
             }
         }
-        // println!("round: {}, monkeys: {:?}", _round + 1, monkeys);
+        year2022::aoc_trace!("round: {}, monkeys: {:?}", _round + 1, monkeys);
     }
 
     monkeys.sort_by(|m1, m2| m2.inspection_count.cmp(&m1.inspection_count));
@@ -1,5 +1,5 @@
 use year2022::grid::{Grid, Position};
-use year2022::search::{search, SearchNode};
+use year2022::search::{search, Search, SearchNode};
 
 pub fn part1() -> String {
     let input: Vec<Vec<char>> = include_str!("res/input12.txt").lines()
@@ -33,20 +33,25 @@ pub fn part2() -> String {
 
     let grid = Grid::new(input);
     let end = find_char(&grid, 'E').unwrap();
-    let distance_fn = |location: Location| { location.height as isize };
-    let cost_fn = |node: &SearchNode<_>| { (node.cost + 1) as isize };
+    let distance_fn = |position: Position| {
+        (abs_diff(position.row, end.row) + abs_diff(position.col, end.col)) as isize
+    };
 
-    let end_location = Location::new(end, &grid);
-    let start_node = SearchNode::new(0, distance_fn(end_location), end_location);
-    let cost = search(start_node, |node| {
+    // Start from every lowest square at once, the first to reach the end is the closest
+    let starts = grid.iter_positions()
+        .map(|p| Location::new(p, &grid))
+        .filter(|l| l.height == 0)
+        .map(|l| SearchNode::new(0, distance_fn(l.position), l));
+    let result = Search::from_starts(starts).run(|node| {
         node.value.position.neighbors(grid.num_rows, grid.num_cols)
             .map(|p| Location::new(p, &grid))
-            .filter(|l| l.height as isize - node.value.height as isize >= -1)
-            .map(|l| { SearchNode::new(cost_fn(node), distance_fn(l), l) })
+            .filter(|l| l.height as isize - node.value.height as isize <= 1)
+            .map(|l| SearchNode::new(node.cost + 1, distance_fn(l.position), l))
             .collect()
-    }).unwrap().cost;
+    });
+    year2022::aoc_debug!("{:?}", result.stats);
 
-    return format!("{:?}", cost);
+    return format!("{:?}", result.solutions[0].node.cost);
 }
 
 fn abs_diff(a: usize, b: usize) -> usize {
@@ -1,7 +1,7 @@
 use recap::Recap;
 use serde::Deserialize;
 
-use year2022::grid::{Grid, Position};
+use year2022::sparse::{self, SparseGrid};
 
 pub fn part1() -> String {
     let paths = include_str!("res/input14.txt").lines()
@@ -25,10 +25,11 @@ pub fn part2() -> String {
 
     let mut wall = build_wall(&paths);
 
-    // Add infinite line
-    let max_y = paths.iter().fold(0, |acc, path| acc.max(path.iter().fold(0, |acc, point| acc.max(point.y))));
-    for x in 0..1000 {
-        wall[&Position::new(max_y + 2, x)] = '#';
+    // Add a floor wide enough to catch all sand, which spreads at most one column per row
+    let bounds = wall.bounds().unwrap();
+    let floor = bounds.max.row + 2;
+    for x in 500 - floor - 1..=500 + floor + 1 {
+        wall.insert(sparse::Point::new(floor, x), '#');
     }
 
     let count = simulate(&mut wall);
@@ -43,18 +44,13 @@ struct Point {
 }
 
 #[allow(dead_code)]
-fn display_wall(grid: &Grid<char>, row_start: usize, row_end: usize, col_start: usize, col_end: usize) {
-    for row in row_start..row_end {
-        for col in col_start..col_end {
-            print!("{}", grid[&Position::new(row, col)])
-        }
-        println!()
-    }
+fn display_wall(wall: &SparseGrid<char>) {
+    println!("{}", wall.render(|c| *c));
     println!()
 }
 
-fn build_wall(paths: &Vec<Vec<Point>>) -> Grid<char> {
-    let mut wall = Grid::new(vec![vec!['.'; 1000]; 1000]);
+fn build_wall(paths: &Vec<Vec<Point>>) -> SparseGrid<char> {
+    let mut wall = SparseGrid::new('.');
     for path in paths {
         let mut iter = path.iter();
         let mut prev = iter.next().unwrap();
@@ -62,11 +58,11 @@ fn build_wall(paths: &Vec<Vec<Point>>) -> Grid<char> {
         loop {
             if prev.x == next.x {
                 for y in prev.y.min(next.y)..=prev.y.max(next.y) {
-                    wall[&Position::new(y, next.x)] = '#'
+                    wall.insert(tile(next.x, y), '#');
                 }
             } else if prev.y == next.y {
                 for x in prev.x.min(next.x)..=prev.x.max(next.x) {
-                    wall[&Position::new(next.y, x)] = '#'
+                    wall.insert(tile(x, next.y), '#');
                 }
             }
             if let Some(it) = iter.next() {
@@ -81,24 +77,30 @@ fn build_wall(paths: &Vec<Vec<Point>>) -> Grid<char> {
     return wall;
 }
 
-fn simulate(wall: &mut Grid<char>) -> i32 {
+fn tile(x: usize, y: usize) -> sparse::Point {
+    return sparse::Point::new(y as isize, x as isize);
+}
+
+fn simulate(wall: &mut SparseGrid<char>) -> i32 {
     let drip = "500,0".parse::<Point>().unwrap();
+    // Sand that falls below the lowest rock falls forever
+    let bottom = wall.bounds().unwrap().max.row as usize;
     let mut count = 0;
     loop {
-        if wall[&Position::new(drip.y, drip.x)] == 'o' {
+        if wall[&tile(drip.x, drip.y)] == 'o' {
             break;
         }
-        // display_wall(&wall, 0, 14, 480, 515);
+        // display_wall(&wall);
 
         let mut sand = drip.clone();
-        while sand.y < 1000 {
-            if wall[&Position::new(sand.y, sand.x)] != '.' {
-                if wall[&Position::new(sand.y, sand.x - 1)] == '.' {
+        while sand.y <= bottom {
+            if wall[&tile(sand.x, sand.y)] != '.' {
+                if wall[&tile(sand.x - 1, sand.y)] == '.' {
                     sand.x -= 1;
-                } else if wall[&Position::new(sand.y, sand.x + 1)] == '.' {
+                } else if wall[&tile(sand.x + 1, sand.y)] == '.' {
                     sand.x += 1;
                 } else {
-                    wall[&Position::new(sand.y - 1, sand.x)] = 'o';
+                    wall.insert(tile(sand.x, sand.y - 1), 'o');
                     count += 1;
                     break;
                 }
@@ -107,11 +109,11 @@ fn simulate(wall: &mut Grid<char>) -> i32 {
             sand.y += 1;
         }
 
-        if sand.y >= 1000 {
+        if sand.y > bottom {
             break
         }
     }
-    // display_wall(&wall, 0, 14, 480, 515);
+    // display_wall(&wall);
 
     return count;
 }
@@ -62,7 +62,7 @@ fn build_memory(max_clock: usize, valves: &Vec<Valve>, valve_tunnel_indexes: &Ve
 
     for clock in 1..max_clock {
         for current_valve_index in 0..valves.len() {
-            // println!("clock={:?} current_valve_index={:?}", clock, current_valve_index);
+            year2022::aoc_trace!("clock={:?} current_valve_index={:?}", clock, current_valve_index);
             let valve = &valves[current_valve_index];
             let current_valve_bit_mask = 1 << current_valve_index;
             for open_valves_bit_mask in 0..permutation_count {
@@ -20,16 +20,16 @@ pub fn part1() -> String {
                 for _ in 0..tiles {
                     let next = layout.movement_flat(&position);
                     if next == position {
-                        // println!("hit a wall");
+                        year2022::aoc_trace!("hit a wall");
                         break
                     }
                     position = next;
-                    // println!("move={:?}", position);
+                    year2022::aoc_trace!("move={:?}", position);
                 }
             }
             Instruction::Turn(turn_right) => {
                 position = layout.turning(&position, turn_right);
-                // println!("turn={:?}", position);
+                year2022::aoc_debug!("turn={:?}", position);
             },
         }
     }
@@ -56,16 +56,16 @@ pub fn part2() -> String {
                 for _ in 0..tiles {
                     let next = layout.movement_cube(&position);
                     if next == position {
-                        // println!("hit a wall");
+                        year2022::aoc_trace!("hit a wall");
                         break
                     }
                     position = next;
-                    // println!("move={:?}", position);
+                    year2022::aoc_trace!("move={:?}", position);
                 }
             }
             Instruction::Turn(turn_right) => {
                 position = layout.turning(&position, turn_right);
-                // println!("turn={:?}", position);
+                year2022::aoc_debug!("turn={:?}", position);
             },
         }
     }
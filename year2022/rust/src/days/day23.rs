@@ -2,35 +2,21 @@ use std::collections::{HashMap, HashSet};
 use std::collections::hash_map::Entry;
 use std::fmt::{Debug, Formatter};
 
-pub fn part1() -> String {
-    let mut grove = Grove::parse(include_str!("res/input23.txt"));
-    for _ in 0..10 {
-        // println!("=== round {} === heading: {:?}", round, grove.heading);
-        // println!("{:?}", grove);
-        // println!();
+use year2022::automaton::{run, run_until, Automaton};
 
-        grove = grove.next();
-    }
+pub fn part1() -> String {
+    let grove = run(Grove::parse(include_str!("res/input23.txt")), 10);
 
-    // println!("=== round 10 === heading: {:?}", grove.heading);
-    // println!("{:?}", grove);
-    // println!();
+    year2022::aoc_debug!("=== round 10 === heading: {:?}\n{:?}", grove.heading, grove);
 
     let (size_x, size_y) = grove.size().unwrap();
     return format!("{:?}", (size_x * size_y - grove.elves.len()));
 }
 
 pub fn part2() -> String {
-    let mut grove = Grove::parse(include_str!("res/input23.txt"));
-    let mut round = 1;
-    loop {
-        let next = grove.next();
-        if next.elves == grove.elves {
-            break;
-        }
-        round += 1;
-        grove = next;
-    }
+    let grove = Grove::parse(include_str!("res/input23.txt"));
+    // The heading keeps turning, so only compare the elves
+    let (_, round) = run_until(grove, |previous, next| previous.elves == next.elves);
     return format!("{:?}", round);
 }
 
@@ -95,7 +81,10 @@ impl Grove {
         return Some(((max_x - min_x + 1) as usize, (max_y - min_y + 1) as usize));
     }
 
-    fn next(&self) -> Self {
+}
+
+impl Automaton for Grove {
+    fn step(&self) -> Self {
         // Map of source elves by their proposed destination
         let mut destinations = HashMap::<Elf, Vec<Elf>>::new();
 
@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use year2022::search::{search, SearchNode};
+use year2022::search::{Search, SearchNode, Strategy};
 
 pub fn part1() -> String {
     let lines = include_str!("res/input24.txt").lines().collect::<Vec<_>>();
@@ -38,7 +38,10 @@ fn get_valley(time: usize, valleys: &mut Vec<Valley>) -> &Valley {
 
 fn find_path(start: &Position, target: &Position, mut valleys: &mut Vec<Valley>, time: usize) -> Option<SearchNode<You>> {
     let start_node = SearchNode::new(time as isize, distance(start, target), You::new(start.clone(), time));
-    return search(start_node, |node| {
+
+    // Every step takes a minute, so a layer holds at most one node per square and a beam this wide never drops a path
+    let width = (valleys[0].rows * valleys[0].cols) as usize + 2;
+    let result = Search::new(start_node).strategy(Strategy::Beam(width)).run(|node| {
         let time = node.cost + 1;
         let valley = get_valley(time as usize, &mut valleys);
         let nodes = node.value.movement(valley)
@@ -46,12 +49,13 @@ fn find_path(start: &Position, target: &Position, mut valleys: &mut Vec<Valley>,
             .map(|you| SearchNode::new(time, distance(&you.position, target), you))
             .collect();
 
-        // println!("node     = {:?}", node);
-        // for n in &nodes { println!("movement = {:?}", n); }
-        // println!();
+        year2022::aoc_trace!("node = {:?}, movements = {:?}", node, nodes);
 
         return nodes;
     });
+    year2022::aoc_debug!("{:?}", result.stats);
+
+    return result.solutions.into_iter().next().map(|solution| solution.node);
 }
 
 fn search_part1(start: &Position, target: &Position, valley: Valley) -> usize {
@@ -1,2 +1,8 @@
+pub mod automaton;
 pub mod grid;
+pub mod grid_algorithms;
+pub mod ocr;
 pub mod search;
+pub mod sparse;
+pub mod tiled;
+pub mod trace;
@@ -9,6 +9,10 @@ mod days;
 struct Cli {
     #[clap()]
     parts: Vec<String>,
+
+    /// Print debug messages, see `AOC_LOG` for finer control
+    #[clap(long)]
+    verbose: bool,
 }
 
 fn main() {
@@ -1,5 +1,5 @@
 use std::cmp::Ordering;
-use std::collections::{BinaryHeap, HashMap};
+use std::collections::{BinaryHeap, HashMap, HashSet};
 use std::hash::Hash;
 
 #[derive(Debug, PartialEq, Eq)]
@@ -29,34 +29,445 @@ impl<T: PartialEq> PartialOrd for SearchNode<T> {
     }
 }
 
+/// Counters of a search run.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct SearchStats {
+    /// Nodes whose neighbors were generated.
+    pub expanded: usize,
+    /// Nodes added to the frontier, including the starts.
+    pub pushed: usize,
+    /// Largest number of nodes waiting in the frontier at once, or the longest path for [`Strategy::IterativeDeepening`].
+    pub max_frontier: usize,
+}
+
+/// A goal node reached by a search.
+#[derive(Debug)]
+pub struct Solution<T> {
+    pub node: SearchNode<T>,
+    /// Index of the first goal predicate that matched, in the order they were added.
+    pub goal: usize,
+    /// The values from a start to the goal, inclusive, if paths were requested.
+    pub path: Option<Vec<T>>,
+}
+
+/// The solutions found, cheapest first, and how much work it took.
+#[derive(Debug)]
+pub struct SearchResult<T> {
+    pub solutions: Vec<Solution<T>>,
+    pub stats: SearchStats,
+}
+
+/// How a [`Search`] explores the nodes, trading memory for speed or for the guarantee of the cheapest path.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Strategy {
+    /// Always expand the node with the lowest cost plus distance, remembering every value it expanded.
+    /// The distance must be consistent: it should never decrease by more than the cost of a step,
+    /// or a value may first be expanded through a more expensive path and the result may not be the cheapest.
+    BestFirst,
+    /// Depth-first under a bound on cost plus distance, raising the bound until a goal is found.
+    /// Only the current path is kept in memory, at the price of expanding nodes again for every bound.
+    IterativeDeepening,
+    /// Breadth-first, keeping only the given number of nodes with the lowest cost plus distance in every layer.
+    /// Memory is bounded by the width, but a beam that is too narrow can miss the cheapest path or every path.
+    /// Values are not remembered between layers, so on a graph with cycles it may not end without a goal in reach.
+    Beam(usize),
+}
+
+type Goal<'a, T> = Box<dyn Fn(&SearchNode<T>) -> bool + 'a>;
+
+/// A search from one or more starts, configured before calling [`Search::run`].
+/// Without any goal predicate a node is a goal when its distance is 0.
+pub struct Search<'a, T> {
+    starts: Vec<SearchNode<T>>,
+    goals: Vec<Goal<'a, T>>,
+    paths: bool,
+    solutions: usize,
+    strategy: Strategy,
+}
+
+/// A frontier node and the index of its parent in the trail.
+struct Entry<T> {
+    node: SearchNode<T>,
+    parent: Option<usize>,
+}
+
+impl<T: Eq> Ord for Entry<T> {
+    fn cmp(&self, other: &Self) -> Ordering { self.node.cmp(&other.node) }
+}
+
+impl<T: Eq> PartialOrd for Entry<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+impl<T: Eq> PartialEq for Entry<T> {
+    fn eq(&self, other: &Self) -> bool { self.cmp(other) == Ordering::Equal }
+}
+
+impl<T: Eq> Eq for Entry<T> {}
+
+impl<'a, T: Hash + Eq + Clone> Search<'a, T> {
+    pub fn new(start: SearchNode<T>) -> Self {
+        return Search::from_starts([start]);
+    }
+
+    /// Search from all of `starts` at once, e.g. to find the nearest of several sources.
+    pub fn from_starts(starts: impl IntoIterator<Item=SearchNode<T>>) -> Self {
+        return Search { starts: starts.into_iter().collect(), goals: vec![], paths: false, solutions: 1, strategy: Strategy::BestFirst };
+    }
+
+    /// Add a start node.
+    pub fn start(mut self, node: SearchNode<T>) -> Self {
+        self.starts.push(node);
+        return self;
+    }
+
+    /// Add a goal predicate, a node is a goal if any of them holds.
+    pub fn goal(mut self, goal: impl Fn(&SearchNode<T>) -> bool + 'a) -> Self {
+        self.goals.push(Box::new(goal));
+        return self;
+    }
+
+    /// Remember the parent of every expanded node, so the solutions include their paths.
+    pub fn with_paths(mut self) -> Self {
+        self.paths = true;
+        return self;
+    }
+
+    /// Stop after `count` solutions instead of the first one.
+    /// Best-first expands every value up to `count` times, so these are the `count` cheapest paths to any goal.
+    /// The other strategies return the first goals they come across, iterative deepening only those within the first bound that reaches a goal.
+    pub fn solutions(mut self, count: usize) -> Self {
+        self.solutions = count;
+        return self;
+    }
+
+    /// Explore with `strategy` instead of [`Strategy::BestFirst`].
+    pub fn strategy(mut self, strategy: Strategy) -> Self {
+        self.strategy = strategy;
+        return self;
+    }
+
+    pub fn run<F>(self, neighbors: F) -> SearchResult<T>
+        where F: FnMut(&SearchNode<T>) -> Vec<SearchNode<T>>
+    {
+        return match self.strategy {
+            Strategy::BestFirst => self.best_first(neighbors),
+            Strategy::IterativeDeepening => self.iterative_deepening(neighbors),
+            Strategy::Beam(width) => self.beam(width, neighbors),
+        };
+    }
+
+    fn best_first<F>(self, mut neighbors: F) -> SearchResult<T>
+        where F: FnMut(&SearchNode<T>) -> Vec<SearchNode<T>>
+    {
+        let mut stats = SearchStats::default();
+        let mut solutions = vec![];
+        let mut expanded = HashMap::<T, usize>::new();
+        let mut trail = Vec::<(T, Option<usize>)>::new();
+        let mut frontier = BinaryHeap::<Entry<T>>::new();
+
+        for node in self.starts {
+            frontier.push(Entry { node, parent: None });
+            stats.pushed += 1;
+        }
+        stats.max_frontier = frontier.len();
+
+        while let Some(Entry { node, parent }) = frontier.pop() {
+            if let Some(goal) = Search::matching_goal(&self.goals, &node) {
+                let path = if self.paths { Some(Search::path(&trail, parent, &node.value)) } else { None };
+                solutions.push(Solution { node, goal, path });
+                if solutions.len() >= self.solutions {
+                    break;
+                }
+                continue;
+            }
+
+            let count = expanded.entry(node.value.clone()).or_insert(0);
+            if *count >= self.solutions {
+                // Already expanded through as many cheaper paths as we need
+                continue;
+            }
+            *count += 1;
+
+            let parent = if self.paths {
+                trail.push((node.value.clone(), parent));
+                Some(trail.len() - 1)
+            } else {
+                None
+            };
+
+            stats.expanded += 1;
+            for neighbor in neighbors(&node) {
+                frontier.push(Entry { node: neighbor, parent });
+                stats.pushed += 1;
+            }
+            stats.max_frontier = stats.max_frontier.max(frontier.len());
+        }
+
+        return SearchResult { solutions, stats };
+    }
+
+    fn iterative_deepening<F>(self, mut neighbors: F) -> SearchResult<T>
+        where F: FnMut(&SearchNode<T>) -> Vec<SearchNode<T>>
+    {
+        let mut stats = SearchStats::default();
+        let mut solutions = vec![];
+        let mut bound = self.starts.iter().map(|node| node.cost + node.distance).min();
+
+        'deepen: while let Some(limit) = bound {
+            // The lowest cost plus distance over the limit, the bound of the next round
+            bound = None;
+
+            for start in &self.starts {
+                let mut stack = Vec::<(SearchNode<T>, std::vec::IntoIter<SearchNode<T>>)>::new();
+                let mut on_path = HashSet::<T>::new();
+                let mut candidate = Some(SearchNode::new(start.cost, start.distance, start.value.clone()));
+                stats.pushed += 1;
+
+                loop {
+                    if let Some(node) = candidate.take() {
+                        let estimate = node.cost + node.distance;
+                        if estimate > limit {
+                            bound = Some(bound.map_or(estimate, |bound: isize| bound.min(estimate)));
+                        } else if on_path.contains(&node.value) {
+                            // Going around in circles
+                        } else if let Some(goal) = Search::matching_goal(&self.goals, &node) {
+                            let path = if self.paths {
+                                Some(stack.iter().map(|(node, _)| node.value.clone()).chain([node.value.clone()]).collect())
+                            } else {
+                                None
+                            };
+                            solutions.push(Solution { node, goal, path });
+                            if solutions.len() >= self.solutions {
+                                break 'deepen;
+                            }
+                        } else {
+                            stats.expanded += 1;
+                            let children = neighbors(&node);
+                            stats.pushed += children.len();
+                            on_path.insert(node.value.clone());
+                            stack.push((node, children.into_iter()));
+                            stats.max_frontier = stats.max_frontier.max(stack.len());
+                        }
+                    }
+
+                    match stack.last_mut() {
+                        None => break,
+                        Some((_, children)) => match children.next() {
+                            Some(child) => candidate = Some(child),
+                            None => {
+                                let (node, _) = stack.pop().unwrap();
+                                on_path.remove(&node.value);
+                            }
+                        },
+                    }
+                }
+            }
+
+            if !solutions.is_empty() {
+                break;
+            }
+        }
+
+        solutions.sort_by_key(|solution| solution.node.cost);
+        return SearchResult { solutions, stats };
+    }
+
+    fn beam<F>(self, width: usize, mut neighbors: F) -> SearchResult<T>
+        where F: FnMut(&SearchNode<T>) -> Vec<SearchNode<T>>
+    {
+        let mut stats = SearchStats::default();
+        let mut solutions = vec![];
+        let mut trail = Vec::<(T, Option<usize>)>::new();
+        let mut layer = self.starts.into_iter().map(|node| Entry { node, parent: None }).collect::<Vec<_>>();
+        stats.pushed = layer.len();
+        stats.max_frontier = layer.len();
+        Search::prune(&mut layer, width);
+
+        while !layer.is_empty() {
+            // The cheapest node for every value in the next layer, in the order they were found
+            let mut next = Vec::<Entry<T>>::new();
+            let mut indices = HashMap::<T, usize>::new();
+
+            for Entry { node, parent } in layer {
+                if let Some(goal) = Search::matching_goal(&self.goals, &node) {
+                    let path = if self.paths { Some(Search::path(&trail, parent, &node.value)) } else { None };
+                    solutions.push(Solution { node, goal, path });
+                    if solutions.len() >= self.solutions {
+                        return SearchResult { solutions, stats };
+                    }
+                    continue;
+                }
+
+                let parent = if self.paths {
+                    trail.push((node.value.clone(), parent));
+                    Some(trail.len() - 1)
+                } else {
+                    None
+                };
+
+                stats.expanded += 1;
+                for neighbor in neighbors(&node) {
+                    stats.pushed += 1;
+                    match indices.get(&neighbor.value) {
+                        Some(&index) if next[index].node.cost <= neighbor.cost => {}
+                        Some(&index) => next[index] = Entry { node: neighbor, parent },
+                        None => {
+                            indices.insert(neighbor.value.clone(), next.len());
+                            next.push(Entry { node: neighbor, parent });
+                        }
+                    }
+                }
+            }
+
+            stats.max_frontier = stats.max_frontier.max(next.len());
+            Search::prune(&mut next, width);
+            layer = next;
+        }
+
+        return SearchResult { solutions, stats };
+    }
+
+    /// Keep the `width` entries with the lowest cost plus distance, cheapest first.
+    fn prune(layer: &mut Vec<Entry<T>>, width: usize) {
+        layer.sort_by_key(|entry| entry.node.cost + entry.node.distance);
+        layer.truncate(width);
+    }
+
+    fn matching_goal(goals: &[Goal<'a, T>], node: &SearchNode<T>) -> Option<usize> {
+        if goals.is_empty() {
+            return if node.distance == 0 { Some(0) } else { None };
+        }
+        return goals.iter().position(|goal| goal(node));
+    }
+
+    fn path(trail: &[(T, Option<usize>)], mut parent: Option<usize>, end: &T) -> Vec<T> {
+        let mut path = vec![end.clone()];
+        while let Some(index) = parent {
+            let (value, next) = &trail[index];
+            path.push(value.clone());
+            parent = *next;
+        }
+        path.reverse();
+        return path;
+    }
+}
+
+/// The cheapest node with a distance of 0 reachable from `start`, see [`Search`] for more options.
 pub fn search<T, F>(
     start: SearchNode<T>,
-    mut neighbors: F,
+    neighbors: F,
 ) -> Option<SearchNode<T>>
-    where T: Hash + Eq,
+    where T: Hash + Eq + Clone,
           F: FnMut(&SearchNode<T>) -> Vec<SearchNode<T>>
 {
-    let mut seen = HashMap::<T, isize>::new();
-    let mut search = BinaryHeap::<SearchNode<T>>::new();
-    search.push(start);
+    return Search::new(start).run(neighbors).solutions.into_iter().next().map(|solution| solution.node);
+}
 
-    while let Some(node) = search.pop() {
-        if seen.contains_key(&node.value) && seen[&node.value] <= node.cost + node.distance {
-            // We've seen the node before and it cost less before
-            continue
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A small directed graph: 0 -1-> 1 -1-> 2 -1-> 3 -1-> 4, with detours 0 -4-> 2, 1 -5-> 3 and 2 -2-> 5.
+    fn graph(node: &SearchNode<u8>) -> Vec<SearchNode<u8>> {
+        let edges: &[(u8, isize)] = match node.value {
+            0 => &[(1, 1), (2, 4)],
+            1 => &[(2, 1), (3, 5)],
+            2 => &[(3, 1), (5, 2)],
+            3 => &[(4, 1)],
+            _ => &[],
+        };
+        return edges.iter().map(|&(value, cost)| SearchNode::new(node.cost + cost, 0, value)).collect();
+    }
+
+    fn start(value: u8) -> SearchNode<u8> {
+        return SearchNode::new(0, 0, value);
+    }
+
+    #[test]
+    fn test_path() {
+        let result = Search::new(start(0)).goal(|node| node.value == 4).with_paths().run(graph);
+        let solution = &result.solutions[0];
+        assert_eq!((solution.node.value, solution.node.cost), (4, 4));
+        assert_eq!(solution.path, Some(vec![0, 1, 2, 3, 4]));
+
+        let result = Search::new(start(0)).goal(|node| node.value == 4).run(graph);
+        assert_eq!(result.solutions[0].path, None);
+    }
+
+    #[test]
+    fn test_goal_index() {
+        let result = Search::new(start(0)).goal(|node| node.value == 5).goal(|node| node.value == 3).run(graph);
+        let solution = &result.solutions[0];
+        assert_eq!((solution.node.value, solution.node.cost, solution.goal), (3, 3, 1));
 
-        if node.distance == 0 {
-            return Some(node);
+        // Without goals, a node with a distance of 0 is a goal.
+        let result = Search::new(SearchNode::new(0, 1, 0)).run(graph);
+        assert_eq!((result.solutions[0].node.value, result.solutions[0].goal), (1, 0));
+    }
+
+    #[test]
+    fn test_multiple_starts() {
+        let result = Search::from_starts([start(0)]).start(start(2)).goal(|node| node.value == 5).with_paths().run(graph);
+        let solution = &result.solutions[0];
+        assert_eq!(solution.node.cost, 2);
+        assert_eq!(solution.path, Some(vec![2, 5]));
+    }
+
+    #[test]
+    fn test_solutions() {
+        let result = Search::new(start(0)).goal(|node| node.value == 3).solutions(3).with_paths().run(graph);
+        let costs = result.solutions.iter().map(|solution| solution.node.cost).collect::<Vec<_>>();
+        assert_eq!(costs, vec![3, 5, 6]);
+        assert_eq!(result.solutions[2].path, Some(vec![0, 1, 3]));
+
+        let result = Search::new(start(0)).goal(|node| node.value == 3).solutions(5).run(graph);
+        assert_eq!(result.solutions.len(), 3);
+    }
+
+    /// A number line where stepping left costs 1 and right costs 3, with a shortcut from 0 to 10.
+    fn line(node: &SearchNode<i64>) -> Vec<SearchNode<i64>> {
+        let mut steps = vec![(node.value - 1, 1), (node.value + 1, 3)];
+        if node.value == 0 {
+            steps.push((10, 5));
         }
+        let distance = |value: i64| ((12 - value) / 2).clamp(0, 6) as isize;
+        return steps.into_iter().map(|(value, cost)| SearchNode::new(node.cost + cost, distance(value), value)).collect();
+    }
 
-        let neighbors = neighbors(&node);
-        seen.insert(node.value,  node.cost + node.distance);
+    #[test]
+    fn test_strategies() {
+        let strategies = [Strategy::BestFirst, Strategy::IterativeDeepening, Strategy::Beam(10)];
+        for strategy in strategies {
+            let result = Search::new(SearchNode::new(0, 6, 0)).goal(|node| node.value == 12).strategy(strategy).with_paths().run(line);
+            let solution = &result.solutions[0];
+            // 0 -> 10 costs 5, then two steps of 3 reach 12
+            assert_eq!((solution.node.value, solution.node.cost), (12, 11), "{:?}", strategy);
+            assert_eq!(solution.path, Some(vec![0, 10, 11, 12]), "{:?}", strategy);
+        }
+    }
 
-        for neighbor in neighbors {
-            search.push(neighbor);
+    #[test]
+    fn test_unreachable_goal() {
+        // Doubling up to 64, so 3 is never reached and every strategy runs out of nodes.
+        let double = |node: &SearchNode<u64>| {
+            if node.value >= 64 { vec![] } else { vec![SearchNode::new(node.cost + 1, 0, node.value * 2)] }
+        };
+        let strategies = [Strategy::BestFirst, Strategy::IterativeDeepening, Strategy::Beam(1)];
+        for strategy in strategies {
+            let result = Search::new(SearchNode::new(0, 0, 1)).goal(|node| node.value == 3).strategy(strategy).run(double);
+            assert!(result.solutions.is_empty(), "{:?}", strategy);
         }
     }
 
-    return None;
+    #[test]
+    fn test_stats() {
+        let line = |node: &SearchNode<u8>| vec![SearchNode::new(node.cost + 1, 0, node.value + 1)];
+        let result = Search::new(start(0)).goal(|node| node.value == 3).run(line);
+        assert_eq!(result.stats, SearchStats { expanded: 3, pushed: 4, max_frontier: 1 });
+
+        // 0, 1 and 2 are expanded, the costlier paths to 2 and 3 are still waiting when 3 is reached.
+        let result = Search::new(start(0)).goal(|node| node.value == 3).run(graph);
+        assert_eq!(result.stats, SearchStats { expanded: 3, pushed: 7, max_frontier: 4 });
+    }
 }
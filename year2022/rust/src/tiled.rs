@@ -0,0 +1,148 @@
+use crate::grid::{Grid, GridView, Position};
+use crate::sparse::Point;
+
+type Transform<'a, T, U> = Box<dyn Fn(&T, Point) -> U + 'a>;
+
+/// A view repeating a grid as tiles, without copying it.
+/// The value of every cell is computed from the value in the grid and the tile it is in.
+pub struct TiledGrid<'a, T, U = T> {
+    grid: &'a Grid<T>,
+    /// Number of tiles down and across.
+    tiles: (usize, usize),
+    transform: Transform<'a, T, U>,
+}
+
+/// A view repeating a grid as tiles forever in every direction, see [`TiledGrid`].
+///
+/// It has no size, so unlike [`TiledGrid`] it is not a [`GridView`]:
+/// the grid algorithms would try to visit every cell of it.
+///
+/// ```compile_fail
+/// use year2022::grid::{Grid, GridView};
+/// use year2022::tiled::InfiniteGrid;
+///
+/// fn size(view: &impl GridView) -> usize {
+///     return view.num_rows() * view.num_cols();
+/// }
+///
+/// let grid = Grid::new(vec![vec!['a', 'b'], vec!['c', 'd']]);
+/// size(&InfiniteGrid::new(&grid, |c, _| *c));
+/// ```
+pub struct InfiniteGrid<'a, T, U = T> {
+    grid: &'a Grid<T>,
+    transform: Transform<'a, T, U>,
+}
+
+impl<'a, T: Clone> TiledGrid<'a, T> {
+    /// Repeat `grid` as is, `tile_rows` times down and `tile_cols` times across.
+    pub fn repeat(grid: &'a Grid<T>, tile_rows: usize, tile_cols: usize) -> Self {
+        return TiledGrid::new(grid, tile_rows, tile_cols, |value, _| value.clone());
+    }
+}
+
+impl<'a, T, U> TiledGrid<'a, T, U> {
+    /// Repeat `grid` `tile_rows` times down and `tile_cols` times across,
+    /// where `transform` maps a value and the (row, column) of its tile to the value in the view.
+    pub fn new(grid: &'a Grid<T>, tile_rows: usize, tile_cols: usize, transform: impl Fn(&T, Point) -> U + 'a) -> Self {
+        return TiledGrid { grid, tiles: (tile_rows, tile_cols), transform: Box::new(transform) };
+    }
+
+    /// The value at any point, `None` outside of the tiles.
+    pub fn get(&self, point: &Point) -> Option<U> {
+        let (tile, position) = locate(self.grid, point);
+        let (tile_rows, tile_cols) = self.tiles;
+        let inside = (0..tile_rows as isize).contains(&tile.row) && (0..tile_cols as isize).contains(&tile.col);
+        if !inside {
+            return None;
+        }
+        return Some((self.transform)(&self.grid[&position], tile));
+    }
+}
+
+impl<'a, T: Clone> InfiniteGrid<'a, T> {
+    /// Repeat `grid` as is, forever in every direction.
+    pub fn repeat(grid: &'a Grid<T>) -> Self {
+        return InfiniteGrid::new(grid, |value, _| value.clone());
+    }
+}
+
+impl<'a, T, U> InfiniteGrid<'a, T, U> {
+    /// Repeat `grid` forever in every direction, see [`TiledGrid::new`] for `transform`.
+    pub fn new(grid: &'a Grid<T>, transform: impl Fn(&T, Point) -> U + 'a) -> Self {
+        return InfiniteGrid { grid, transform: Box::new(transform) };
+    }
+
+    /// The value at any point, wrapping around into the tiles at negative coordinates.
+    pub fn get(&self, point: &Point) -> U {
+        let (tile, position) = locate(self.grid, point);
+        return (self.transform)(&self.grid[&position], tile);
+    }
+}
+
+/// The tile a point lies in, and its position within that tile.
+fn locate<T>(grid: &Grid<T>, point: &Point) -> (Point, Position) {
+    let rows = grid.num_rows as isize;
+    let cols = grid.num_cols as isize;
+    let tile = Point::new(point.row.div_euclid(rows), point.col.div_euclid(cols));
+    let position = Position::new(point.row.rem_euclid(rows) as usize, point.col.rem_euclid(cols) as usize);
+    return (tile, position);
+}
+
+impl<'a, T, U> GridView for TiledGrid<'a, T, U> {
+    type Value = U;
+
+    fn num_rows(&self) -> usize {
+        return self.tiles.0 * self.grid.num_rows;
+    }
+
+    fn num_cols(&self) -> usize {
+        return self.tiles.1 * self.grid.num_cols;
+    }
+
+    fn value(&self, position: &Position) -> U {
+        let tile = Point::new((position.row / self.grid.num_rows) as isize, (position.col / self.grid.num_cols) as isize);
+        let position = Position::new(position.row % self.grid.num_rows, position.col % self.grid.num_cols);
+        return (self.transform)(&self.grid[&position], tile);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Neighborhood;
+    use crate::grid_algorithms::distances;
+
+    #[test]
+    fn test_tiles() {
+        let grid = Grid::new(vec![vec![1, 8], vec![9, 2]]);
+        let tiled = TiledGrid::new(&grid, 2, 3, |v, tile| (v + tile.row as usize + tile.col as usize - 1) % 9 + 1);
+
+        assert_eq!((tiled.num_rows(), tiled.num_cols()), (4, 6));
+        assert_eq!(tiled.value(&Position::new(0, 2)), 2);
+        assert_eq!(tiled.value(&Position::new(3, 5)), 5);
+        assert_eq!(tiled.get(&Point::new(2, 3)), Some(1));
+        assert_eq!(tiled.get(&Point::new(-1, 0)), None);
+        assert_eq!(tiled.get(&Point::new(4, 0)), None);
+    }
+
+    #[test]
+    fn test_infinite() {
+        let grid = Grid::new(vec![vec!['a', 'b'], vec!['c', 'd']]);
+        let infinite = InfiniteGrid::repeat(&grid);
+        assert_eq!(infinite.get(&Point::new(-1, -1)), 'd');
+        assert_eq!(infinite.get(&Point::new(100, 3)), 'b');
+        assert_eq!(infinite.get(&Point::new(-1_000_000_001, 1_000_000)), 'c');
+
+        let shifted = InfiniteGrid::new(&grid, |c, tile| (tile.row, tile.col, *c));
+        assert_eq!(shifted.get(&Point::new(-3, 4)), (-2, 2, 'c'));
+    }
+
+    #[test]
+    fn test_distances() {
+        let grid = Grid::new(vec![vec!['.', '#'], vec!['.', '.']]);
+        let tiled = TiledGrid::repeat(&grid, 2, 2);
+        let distances = distances(&tiled, &[Position::new(0, 0)], Neighborhood::Orthogonal, |_, to| *to == '.');
+        assert_eq!(distances[&Position::new(2, 2)], Some(4));
+        assert_eq!(distances[&Position::new(0, 3)], None);
+    }
+}
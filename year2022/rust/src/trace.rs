@@ -0,0 +1,138 @@
+use std::env;
+use std::fmt;
+use std::sync::OnceLock;
+
+/// Verbosity of a trace message, from least to most verbose.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Off,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    fn parse(s: &str) -> Option<Level> {
+        return match s.trim().to_lowercase().as_str() {
+            "off" => Some(Level::Off),
+            "debug" => Some(Level::Debug),
+            "trace" => Some(Level::Trace),
+            _ => None,
+        };
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            Level::Off => write!(f, "OFF"),
+            Level::Debug => write!(f, "DEBUG"),
+            Level::Trace => write!(f, "TRACE"),
+        };
+    }
+}
+
+/// Which messages to print, parsed from `AOC_LOG`, e.g. `debug`, `day22=trace` or `debug,snailfish=off`.
+/// A directive without a target sets the default level, `--verbose` raises the default to `debug`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Filter {
+    default: Level,
+    targets: Vec<(String, Level)>,
+}
+
+impl Filter {
+    pub fn parse(spec: &str, verbose: bool) -> Filter {
+        let mut default = if verbose { Level::Debug } else { Level::Off };
+        let mut targets = Vec::new();
+
+        for directive in spec.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                Some((target, level)) => {
+                    if let Some(level) = Level::parse(level) {
+                        targets.push((target.trim().to_string(), level));
+                    }
+                }
+                None => {
+                    if let Some(level) = Level::parse(directive) {
+                        default = default.max(level);
+                    }
+                }
+            }
+        }
+
+        return Filter { default, targets };
+    }
+
+    /// Whether a message of `level` logged from `module_path` passes the filter.
+    /// Targets match any segment of the module path, e.g. `day22` matches `aoc2022::days::day22`.
+    pub fn enabled(&self, level: Level, module_path: &str) -> bool {
+        let max_level = self
+            .targets
+            .iter()
+            .rev()
+            .find(|(target, _)| module_path.split("::").any(|segment| segment == target))
+            .map_or(self.default, |(_, level)| *level);
+        return level != Level::Off && level <= max_level;
+    }
+}
+
+fn filter() -> &'static Filter {
+    static FILTER: OnceLock<Filter> = OnceLock::new();
+    return FILTER.get_or_init(|| {
+        let spec = env::var("AOC_LOG").unwrap_or_default();
+        let verbose = env::args().any(|arg| arg == "--verbose");
+        return Filter::parse(&spec, verbose);
+    });
+}
+
+pub fn enabled(level: Level, module_path: &str) -> bool {
+    return filter().enabled(level, module_path);
+}
+
+/// Print a message to stderr if `level` is enabled for the calling module. Compiled out of release builds.
+#[macro_export]
+macro_rules! aoc_log {
+    ($level:expr, $($arg:tt)+) => {
+        if cfg!(debug_assertions) && $crate::trace::enabled($level, module_path!()) {
+            eprintln!("[{} {}] {}", $level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+/// Print a debug message, see [`aoc_log`].
+#[macro_export]
+macro_rules! aoc_debug {
+    ($($arg:tt)+) => {
+        $crate::aoc_log!($crate::trace::Level::Debug, $($arg)+)
+    };
+}
+
+/// Print a trace message, see [`aoc_log`].
+#[macro_export]
+macro_rules! aoc_trace {
+    ($($arg:tt)+) => {
+        $crate::aoc_log!($crate::trace::Level::Trace, $($arg)+)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filters_by_level_and_target() {
+        let filter = Filter::parse("debug,day22=trace,snailfish=off", false);
+        assert!(filter.enabled(Level::Debug, "day10"));
+        assert!(!filter.enabled(Level::Trace, "day10"));
+        assert!(filter.enabled(Level::Trace, "aoc2022::days::day22"));
+        assert!(!filter.enabled(Level::Debug, "year2021::snailfish"));
+        assert!(!filter.enabled(Level::Trace, "day2"));
+    }
+
+    #[test]
+    fn verbose_enables_debug() {
+        assert!(!Filter::parse("", false).enabled(Level::Debug, "day01"));
+        assert!(Filter::parse("", true).enabled(Level::Debug, "day01"));
+        assert!(!Filter::parse("", true).enabled(Level::Trace, "day01"));
+        assert!(Filter::parse("trace", true).enabled(Level::Trace, "day01"));
+    }
+}
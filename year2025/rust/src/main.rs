@@ -1,13 +1,15 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, download, leaderboard, read, report, scaffold, serve, snapshots, solve, status, time,
+};
 use args::{AppArguments, parse};
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
-#[cfg(feature = "today")]
-use std::process;
+use advent_of_code::template::commands::today;
 
 mod args {
     use advent_of_code::template::Day;
+    use advent_of_code::template::commands::{scaffold, serve};
+    use advent_of_code::template::report::{Format, SortBy};
     use std::process;
 
     pub enum AppArguments {
@@ -21,15 +23,40 @@ mod args {
             day: Day,
             download: bool,
             overwrite: bool,
+            options: scaffold::Options,
         },
         Solve {
             day: Day,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            force: bool,
+            ocr: bool,
+            repeat: usize,
+            verbose: bool,
         },
         All {
             release: bool,
+            repeat: usize,
+            verbose: bool,
+        },
+        Status,
+        Report {
+            format: Format,
+            sort_by: SortBy,
+            output: Option<String>,
+        },
+        Snapshots {
+            day: Option<Day>,
+            update: bool,
+        },
+        Leaderboard {
+            id: u64,
+            file: Option<String>,
+            refresh: bool,
+        },
+        Serve {
+            port: u16,
         },
         Time {
             all: bool,
@@ -37,7 +64,9 @@ mod args {
             store: bool,
         },
         #[cfg(feature = "today")]
-        Today,
+        Today {
+            wait: bool,
+        },
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
@@ -46,6 +75,19 @@ mod args {
         let app_args = match args.subcommand()?.as_deref() {
             Some("all") => AppArguments::All {
                 release: args.contains("--release"),
+                repeat: args.opt_value_from_str("--repeat")?.unwrap_or(1),
+                verbose: args.contains("--verbose"),
+            },
+            Some("status") => AppArguments::Status,
+            Some("leaderboard") => AppArguments::Leaderboard {
+                file: args.opt_value_from_str("--file")?,
+                refresh: args.contains("--refresh"),
+                id: args.free_from_str()?,
+            },
+            Some("serve") => AppArguments::Serve {
+                port: args
+                    .opt_value_from_str("--port")?
+                    .unwrap_or(serve::DEFAULT_PORT),
             },
             Some("time") => {
                 let all = args.contains("--all");
@@ -67,15 +109,40 @@ mod args {
                 day: args.free_from_str()?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
+                options: scaffold::Options {
+                    template: args.opt_value_from_str("--template")?,
+                    dry_run: args.contains("--dry-run"),
+                    example_answers: [
+                        args.opt_value_from_str("--example-1")?,
+                        args.opt_value_from_str("--example-2")?,
+                    ],
+                },
+            },
+            Some("report") => AppArguments::Report {
+                format: args
+                    .opt_value_from_str("--format")?
+                    .unwrap_or(Format::Markdown),
+                sort_by: args.opt_value_from_str("--sort")?.unwrap_or_default(),
+                output: args.opt_value_from_str("--output")?,
+            },
+            Some("snapshots") => AppArguments::Snapshots {
+                update: args.contains("--update-snapshots"),
+                day: args.opt_free_from_str()?,
             },
             Some("solve") => AppArguments::Solve {
                 day: args.free_from_str()?,
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
                 dhat: args.contains("--dhat"),
+                force: args.contains("--force"),
+                ocr: args.contains("--ocr"),
+                repeat: args.opt_value_from_str("--repeat")?.unwrap_or(1),
+                verbose: args.contains("--verbose"),
             },
             #[cfg(feature = "today")]
-            Some("today") => AppArguments::Today,
+            Some("today") => AppArguments::Today {
+                wait: args.contains("--wait"),
+            },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
                 process::exit(1);
@@ -102,7 +169,22 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
+            AppArguments::All {
+                release,
+                repeat,
+                verbose,
+            } => all::handle(release, repeat, verbose),
+            AppArguments::Status => status::handle(),
+            AppArguments::Report {
+                format,
+                sort_by,
+                output,
+            } => report::handle(format, sort_by, output),
+            AppArguments::Snapshots { day, update } => snapshots::handle(day, update),
+            AppArguments::Leaderboard { id, file, refresh } => {
+                leaderboard::handle(id, file, refresh);
+            }
+            AppArguments::Serve { port } => serve::handle(port),
             AppArguments::Time { day, all, store } => time::handle(day, all, store),
             AppArguments::Download { day } => download::handle(day),
             AppArguments::Read { day } => read::handle(day),
@@ -110,35 +192,26 @@ fn main() {
                 day,
                 download,
                 overwrite,
+                options,
             } => {
-                scaffold::handle(day, overwrite);
-                if download {
+                // download first, so the template can use the title of the puzzle.
+                if download && !options.dry_run {
                     download::handle(day);
                 }
+                scaffold::handle(day, overwrite, &options);
             }
             AppArguments::Solve {
                 day,
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                force,
+                ocr,
+                repeat,
+                verbose,
+            } => solve::handle(day, release, dhat, submit, force, ocr, repeat, verbose),
             #[cfg(feature = "today")]
-            AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
-                    }
-                    None => {
-                        eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
-                        );
-                        process::exit(1)
-                    }
-                };
-            }
+            AppArguments::Today { wait } => today::handle(wait),
         },
     };
 }
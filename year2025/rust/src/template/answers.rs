@@ -0,0 +1,215 @@
+use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::Day;
+
+static ANSWERS_FILE_PATH: &str = "./data/answers.json";
+
+/// Represents the accepted answers for a single day.
+#[derive(Clone, Debug)]
+pub struct Answer {
+    pub day: Day,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+/// Represents the accepted answers for a set of days.
+/// Every recorded answer corresponds to a star.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    pub data: Vec<Answer>,
+}
+
+impl Answers {
+    /// Dehydrate answers to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(ANSWERS_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate answers from a JSON file. If not present, returns empty answers.
+    pub fn read_from_file() -> Self {
+        fs::read_to_string(ANSWERS_FILE_PATH)
+            .map_err(|x| x.to_string())
+            .and_then(Answers::try_from)
+            .unwrap_or_default()
+    }
+
+    /// Record the accepted answer for one part of a day, replacing any previous answer.
+    pub fn record(&mut self, day: Day, part: u8, answer: &str) {
+        let index = match self.data.iter().position(|a| a.day == day) {
+            Some(index) => index,
+            None => {
+                self.data.push(Answer {
+                    day,
+                    part_1: None,
+                    part_2: None,
+                });
+                self.data.sort_unstable_by_key(|a| a.day);
+                self.data.iter().position(|a| a.day == day).unwrap()
+            }
+        };
+
+        let answer = Some(answer.to_string());
+        match part {
+            1 => self.data[index].part_1 = answer,
+            _ => self.data[index].part_2 = answer,
+        }
+    }
+
+    /// Returns the accepted answer for one part of a day, if any.
+    pub fn get(&self, day: Day, part: u8) -> Option<&str> {
+        let answer = self.data.iter().find(|a| a.day == day)?;
+        match part {
+            1 => answer.part_1.as_deref(),
+            _ => answer.part_2.as_deref(),
+        }
+    }
+
+    /// Number of stars (0, 1 or 2) collected for a day.
+    pub fn stars(&self, day: Day) -> u8 {
+        u8::from(self.get(day, 1).is_some()) + u8::from(self.get(day, 2).is_some())
+    }
+
+    /// Number of stars collected across all days.
+    pub fn total_stars(&self) -> u32 {
+        self.data.iter().map(|a| u32::from(self.stars(a.day))).sum()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Answers> for JsonValue {
+    fn from(value: Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Answers {
+            data: json_data
+                .iter()
+                .map(Answer::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Answer> for JsonValue {
+    fn from(value: &Answer) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+
+        map.insert(
+            "part_1".into(),
+            value
+                .part_1
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        map.insert(
+            "part_2".into(),
+            value
+                .part_2
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Answer {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected answer to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected answer.day to be a Day struct.")?;
+
+        let part_1 = json
+            .get("part_1")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answer.part_1 to be null or string.")?;
+
+        let part_2 = json
+            .get("part_2")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answer.part_2 to be null or string.")?;
+
+        Ok(Answer {
+            day,
+            part_1: part_1.cloned(),
+            part_2: part_2.cloned(),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use crate::{day, template::answers::Answers};
+
+    #[test]
+    fn handles_json_answers() {
+        let json = r#"{ "data": [{ "day": "03", "part_1": "42", "part_2": null }] }"#.to_string();
+        let answers = Answers::try_from(json).unwrap();
+        assert_eq!(answers.get(day!(3), 1), Some("42"));
+        assert_eq!(answers.get(day!(3), 2), None);
+        assert_eq!(answers.stars(day!(3)), 1);
+        assert_eq!(answers.stars(day!(4)), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_answers() {
+        let json = r#"{ "data": [{ "day": "26", "part_1": null, "part_2": null }] }"#.to_string();
+        Answers::try_from(json).unwrap();
+    }
+
+    #[test]
+    fn records_answers_in_day_order() {
+        let mut answers = Answers::default();
+        answers.record(day!(5), 1, "10");
+        answers.record(day!(2), 2, "20");
+        answers.record(day!(5), 2, "30");
+
+        assert_eq!(answers.data.len(), 2);
+        assert_eq!(answers.data[0].day, day!(2));
+        assert_eq!(answers.stars(day!(5)), 2);
+        assert_eq!(answers.total_stars(), 3);
+    }
+}
@@ -5,6 +5,7 @@ use std::{
 };
 
 use crate::template::Day;
+use crate::template::remote::Throttle;
 
 #[derive(Debug)]
 pub enum AocCommandError {
@@ -49,16 +50,15 @@ pub fn read(day: Day) -> Result<Output, AocCommandError> {
     call_aoc_cli(&args)
 }
 
-pub fn download(day: Day) -> Result<Output, AocCommandError> {
-    let input_path = get_input_path(day);
+/// Download only the puzzle description, leaving the input untouched.
+pub fn download_puzzle(day: Day) -> Result<Output, AocCommandError> {
     let puzzle_path = get_puzzle_path(day);
 
     let args = build_args(
         "download",
         &[
             "--overwrite".into(),
-            "--input-file".into(),
-            input_path.to_string(),
+            "--puzzle-only".into(),
             "--puzzle-file".into(),
             puzzle_path.to_string(),
         ],
@@ -66,29 +66,19 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
     );
 
     let output = call_aoc_cli(&args)?;
-    println!("---");
-    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
     println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
     Ok(output)
 }
 
-pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
-    // workaround: the argument order is inverted for submit.
-    let mut args = build_args("submit", &[], day);
-    args.push(part.to_string());
-    args.push(result.to_string());
-    call_aoc_cli(&args)
-}
-
-fn get_input_path(day: Day) -> String {
+pub fn get_input_path(day: Day) -> String {
     format!("data/inputs/{day}.txt")
 }
 
-fn get_puzzle_path(day: Day) -> String {
+pub fn get_puzzle_path(day: Day) -> String {
     format!("data/puzzles/{day}.md")
 }
 
-fn get_year() -> Option<u16> {
+pub fn get_year() -> Option<u16> {
     match std::env::var("AOC_YEAR") {
         Ok(x) => x.parse().ok().or(None),
         Err(_) => None,
@@ -109,7 +99,8 @@ fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
 }
 
 fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
-    // println!("Calling >aoc with: {}", args.join(" "));
+    crate::aoc_debug!("Calling >aoc with: {}", args.join(" "));
+    Throttle::from_env().wait();
     let output = Command::new("aoc")
         .args(args)
         .stdout(Stdio::inherit())
@@ -1,16 +1,33 @@
+use crate::template::remote::{Client, RequestError};
 use crate::template::{Day, aoc_cli};
 use std::process;
 
-pub fn handle(day: Day) {
+/// Download the input through the shared request layer and the puzzle description through aoc-cli.
+pub fn fetch(day: Day) -> Result<(), String> {
+    let year = aoc_cli::get_year().ok_or_else(|| RequestError::NoYear.to_string())?;
+    let input_path = aoc_cli::get_input_path(day);
+
+    match Client::from_env().download_input(day, year, &input_path) {
+        Ok(true) => println!("🎄 Successfully wrote input to \"{input_path}\"."),
+        Ok(false) => println!("🎄 Input \"{input_path}\" is already present."),
+        Err(e) => return Err(format!("failed to download input: {e}")),
+    }
+
     if aoc_cli::check().is_err() {
-        eprintln!(
+        return Err(
             "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
+                .into(),
         );
-        process::exit(1);
     }
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    aoc_cli::download_puzzle(day)
+        .map(|_| ())
+        .map_err(|e| format!("failed to call aoc-cli: {e}"))
+}
+
+pub fn handle(day: Day) {
+    if let Err(e) = fetch(day) {
+        eprintln!("{e}");
         process::exit(1);
-    };
+    }
 }
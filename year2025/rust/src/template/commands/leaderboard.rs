@@ -0,0 +1,54 @@
+use std::{
+    fs, process,
+    time::{Duration, SystemTime},
+};
+
+use crate::template::aoc_cli;
+use crate::template::leaderboard::Leaderboard;
+use crate::template::remote::{Client, RequestError};
+
+static CACHE_DIR: &str = "data/leaderboards";
+
+/// Advent of Code asks to not request private leaderboards more than once every 15 minutes.
+const CACHE_DURATION: Duration = Duration::from_secs(15 * 60);
+
+fn is_fresh(path: &str) -> bool {
+    fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .is_some_and(|age| age < CACHE_DURATION)
+}
+
+fn fetch(id: u64, cache_path: &str) -> Result<String, String> {
+    let year = aoc_cli::get_year().ok_or_else(|| RequestError::NoYear.to_string())?;
+
+    let json = Client::from_env()
+        .get(&format!("/{year}/leaderboard/private/view/{id}.json"))
+        .map_err(|e| e.to_string())?;
+
+    fs::create_dir_all(CACHE_DIR).map_err(|e| e.to_string())?;
+    fs::write(cache_path, &json).map_err(|e| e.to_string())?;
+    Ok(json)
+}
+
+/// Read the leaderboard from `file` if given, otherwise from the cache or the website.
+pub fn handle(id: u64, file: Option<String>, refresh: bool) {
+    let cache_path = format!("{CACHE_DIR}/{id}.json");
+
+    let json = match file {
+        Some(file) => fs::read_to_string(file).map_err(|e| e.to_string()),
+        None if !refresh && is_fresh(&cache_path) => {
+            fs::read_to_string(&cache_path).map_err(|e| e.to_string())
+        }
+        None => fetch(id, &cache_path),
+    };
+
+    match json.and_then(Leaderboard::try_from) {
+        Ok(leaderboard) => print!("{}", leaderboard.render()),
+        Err(e) => {
+            eprintln!("Failed to load leaderboard: {e}");
+            process::exit(1);
+        }
+    }
+}
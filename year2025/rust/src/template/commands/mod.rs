@@ -1,6 +1,13 @@
 pub mod all;
 pub mod download;
+pub mod leaderboard;
 pub mod read;
+pub mod report;
 pub mod scaffold;
+pub mod serve;
+pub mod snapshots;
 pub mod solve;
+pub mod status;
 pub mod time;
+#[cfg(feature = "today")]
+pub mod today;
@@ -1,8 +1,20 @@
 use std::process;
 
-use crate::template::{Day, aoc_cli};
+use crate::template::answers::Answers;
+use crate::template::{Day, aoc_cli, puzzles};
 
+/// Render the stored puzzle description. Only calls aoc-cli if the puzzle was not downloaded yet,
+/// or if part one has been solved, here or on the website, but the stored description predates part two.
 pub fn handle(day: Day) {
+    let answers = Answers::read_from_file();
+
+    if let Some(markdown) = puzzles::read(day)
+        && !puzzles::is_outdated(day, &answers, &markdown)
+    {
+        println!("{}", puzzles::render(&markdown));
+        return;
+    }
+
     if aoc_cli::check().is_err() {
         eprintln!(
             "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
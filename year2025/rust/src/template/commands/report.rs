@@ -0,0 +1,31 @@
+use std::{fs, process};
+
+use crate::template::history::History;
+use crate::template::report::{Format, Report, SortBy};
+use crate::template::timings::Timings;
+
+/// Render the stored timings to `output`, or to stdout if not given.
+pub fn handle(format: Format, sort_by: SortBy, output: Option<String>) {
+    let timings = Timings::read_from_file();
+    let history = History::read_from_file();
+
+    let mut report = Report::new(&timings)
+        .with_history(&history)
+        .sorted_by(sort_by)
+        .render(format);
+
+    if !report.ends_with('\n') {
+        report.push('\n');
+    }
+
+    match output {
+        Some(path) => {
+            if let Err(e) = fs::write(&path, report) {
+                eprintln!("Failed to write report to {path}: {e}");
+                process::exit(1);
+            }
+            println!("Wrote report to {path}.");
+        }
+        None => print!("{report}"),
+    }
+}
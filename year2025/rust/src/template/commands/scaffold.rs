@@ -1,14 +1,150 @@
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs::{self, File, OpenOptions},
+    io::{ErrorKind, Write},
     process,
 };
 
-use crate::template::Day;
+use crate::template::{Day, aoc_cli};
 
 const MODULE_TEMPLATE: &str =
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
 
+/// Templates that ship with the repository, selectable via `--template <name>`.
+const BUILTIN_TEMPLATES: [(&str, &str); 5] = [
+    ("default", MODULE_TEMPLATE),
+    (
+        "grid",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/grid.txt"
+        )),
+    ),
+    (
+        "lines",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/lines.txt"
+        )),
+    ),
+    (
+        "graph",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/graph.txt"
+        )),
+    ),
+    (
+        "shared",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/templates/shared.txt"
+        )),
+    ),
+];
+
+/// User templates, `templates/<name>.txt`. These take precedence over built-in templates.
+static USER_TEMPLATES_DIR: &str = "templates";
+
+#[derive(Debug, Default)]
+pub struct Options {
+    pub template: Option<String>,
+    pub dry_run: bool,
+    /// Expected example answers for part one and two.
+    pub example_answers: [Option<String>; 2],
+}
+
+/// Values substituted into a template.
+struct Placeholders {
+    day: Day,
+    year: Option<u16>,
+    title: Option<String>,
+    example_answers: [Option<String>; 2],
+}
+
+/// Returns the names of all available templates, user templates included.
+pub fn template_names() -> Vec<String> {
+    let mut names: Vec<String> = BUILTIN_TEMPLATES
+        .iter()
+        .map(|(name, _)| (*name).to_string())
+        .collect();
+
+    if let Ok(entries) = fs::read_dir(USER_TEMPLATES_DIR) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().is_some_and(|ext| ext == "txt")
+                && let Some(name) = path.file_stem().and_then(|s| s.to_str())
+                && !names.iter().any(|n| n == name)
+            {
+                names.push(name.to_string());
+            }
+        }
+    }
+
+    names.sort_unstable();
+    names
+}
+
+fn load_template(name: &str) -> Result<String, String> {
+    if let Ok(template) = fs::read_to_string(format!("{USER_TEMPLATES_DIR}/{name}.txt")) {
+        return Ok(template);
+    }
+
+    BUILTIN_TEMPLATES
+        .iter()
+        .find(|(builtin, _)| *builtin == name)
+        .map(|(_, template)| (*template).to_string())
+        .ok_or_else(|| {
+            format!(
+                "unknown template \"{name}\". Available templates: {}.",
+                template_names().join(", ")
+            )
+        })
+}
+
+/// Extracts the title from a puzzle file written by aoc-cli, e.g. `\--- Day 1: Title ---`.
+fn parse_title(puzzle: &str) -> Option<String> {
+    let line = puzzle.lines().find(|line| line.contains("--- Day "))?;
+    let (_, title) = line.split_once(": ")?;
+    let title = title.replace('\\', "");
+    let title = title.trim().trim_end_matches('-').trim();
+    (!title.is_empty()).then(|| title.to_string())
+}
+
+/// An example answer as a Rust expression: integers as they are, anything else as a string literal,
+/// e.g. for answers read from block letters.
+fn example_answer(answer: Option<&str>) -> String {
+    match answer {
+        None => "None".to_string(),
+        Some(answer) if answer.parse::<i128>().is_ok() => format!("Some({answer})"),
+        Some(answer) => format!("Some({answer:?}.into())"),
+    }
+}
+
+fn render(template: &str, placeholders: &Placeholders) -> String {
+    template
+        .replace("%DAY_NUMBER%", &placeholders.day.into_inner().to_string())
+        .replace("%DAY%", &placeholders.day.to_string())
+        .replace(
+            "%YEAR%",
+            &placeholders.year.map(|y| y.to_string()).unwrap_or_default(),
+        )
+        .replace(
+            "%TITLE%",
+            placeholders
+                .title
+                .as_deref()
+                .unwrap_or(&format!("Day {}", placeholders.day.into_inner())),
+        )
+        .replace(
+            "%EXAMPLE_ANSWER_1%",
+            &example_answer(placeholders.example_answers[0].as_deref()),
+        )
+        .replace(
+            "%EXAMPLE_ANSWER_2%",
+            &example_answer(placeholders.example_answers[1].as_deref()),
+        )
+}
+
 fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
     if overwrite {
@@ -19,19 +155,46 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
     file.truncate(true).write(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
+/// Create an empty file unless it exists, so an input downloaded before scaffolding is kept.
+/// Returns whether the file was created.
+fn create_file(path: &str) -> Result<bool, std::io::Error> {
+    match OpenOptions::new().write(true).create_new(true).open(path) {
+        Ok(_) => Ok(true),
+        Err(e) if e.kind() == ErrorKind::AlreadyExists => Ok(false),
+        Err(e) => Err(e),
+    }
 }
 
-pub fn handle(day: Day, overwrite: bool) {
+pub fn handle(day: Day, overwrite: bool, options: &Options) {
     let input_path = format!("data/inputs/{day}.txt");
     let example_path = format!("data/examples/{day}.txt");
     let module_path = format!("src/bin/{day}.rs");
 
+    let template = match load_template(options.template.as_deref().unwrap_or("default")) {
+        Ok(template) => template,
+        Err(e) => {
+            eprintln!("Failed to load template: {e}");
+            process::exit(1);
+        }
+    };
+
+    let contents = render(
+        &template,
+        &Placeholders {
+            day,
+            year: aoc_cli::get_year(),
+            title: fs::read_to_string(format!("data/puzzles/{day}.md"))
+                .ok()
+                .and_then(|puzzle| parse_title(&puzzle)),
+            example_answers: options.example_answers.clone(),
+        },
+    );
+
+    if options.dry_run {
+        println!("{contents}");
+        return;
+    }
+
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
         Err(e) => {
@@ -40,11 +203,7 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    match file.write_all(contents.as_bytes()) {
         Ok(()) => {
             println!("Created module file \"{}\"", &module_path);
         }
@@ -55,9 +214,12 @@ pub fn handle(day: Day, overwrite: bool) {
     }
 
     match create_file(&input_path) {
-        Ok(_) => {
+        Ok(true) => {
             println!("Created empty input file \"{}\"", &input_path);
         }
+        Ok(false) => {
+            println!("Kept existing input file \"{}\"", &input_path);
+        }
         Err(e) => {
             eprintln!("Failed to create input file: {e}");
             process::exit(1);
@@ -65,9 +227,12 @@ pub fn handle(day: Day, overwrite: bool) {
     }
 
     match create_file(&example_path) {
-        Ok(_) => {
+        Ok(true) => {
             println!("Created empty example file \"{}\"", &example_path);
         }
+        Ok(false) => {
+            println!("Kept existing example file \"{}\"", &example_path);
+        }
         Err(e) => {
             eprintln!("Failed to create example file: {e}");
             process::exit(1);
@@ -77,3 +242,83 @@ pub fn handle(day: Day, overwrite: bool) {
     println!("---");
     println!("🎄 Type `cargo solve {day}` to run your solution.");
 }
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{BUILTIN_TEMPLATES, Placeholders, example_answer, parse_title, render};
+    use crate::day;
+
+    #[test]
+    fn renders_placeholders() {
+        let rendered = render(
+            "%DAY_NUMBER% %DAY% %YEAR% %TITLE% %EXAMPLE_ANSWER_1% %EXAMPLE_ANSWER_2%",
+            &Placeholders {
+                day: day!(7),
+                year: Some(2025),
+                title: Some("Laboratories".into()),
+                example_answers: [Some("21".into()), None],
+            },
+        );
+        assert_eq!(rendered, "7 07 2025 Laboratories Some(21) None");
+    }
+
+    #[test]
+    fn quotes_non_integer_answers() {
+        let rendered = render(
+            "%EXAMPLE_ANSWER_1% %EXAMPLE_ANSWER_2%",
+            &Placeholders {
+                day: day!(7),
+                year: None,
+                title: None,
+                example_answers: [Some("-3".into()), Some("EFJ".into())],
+            },
+        );
+        assert_eq!(rendered, "Some(-3) Some(\"EFJ\".into())");
+        assert_eq!(
+            example_answer(Some("6,4,\"x\"")),
+            "Some(\"6,4,\\\"x\\\"\".into())"
+        );
+    }
+
+    #[test]
+    fn falls_back_to_day_title() {
+        let rendered = render(
+            "%TITLE%",
+            &Placeholders {
+                day: day!(7),
+                year: None,
+                title: None,
+                example_answers: [None, None],
+            },
+        );
+        assert_eq!(rendered, "Day 7");
+    }
+
+    #[test]
+    fn renders_all_builtin_templates() {
+        for (name, template) in BUILTIN_TEMPLATES {
+            let rendered = render(
+                template,
+                &Placeholders {
+                    day: day!(1),
+                    year: Some(2025),
+                    title: Some("Secret Entrance".into()),
+                    example_answers: [None, None],
+                },
+            );
+            assert!(!rendered.contains('%'), "unreplaced placeholder in {name}");
+            assert!(rendered.starts_with("advent_of_code::solution!(1);"));
+            assert!(
+                rendered.contains("// Secret Entrance: https://adventofcode.com/2025/day/1"),
+                "no title line in {name}"
+            );
+        }
+    }
+
+    #[test]
+    fn parses_puzzle_title() {
+        let puzzle = "\\--- Day 1: Secret Entrance ---\n-----------\n\nThe Elves...";
+        assert_eq!(parse_title(puzzle), Some("Secret Entrance".into()));
+        assert_eq!(parse_title("no title here"), None);
+    }
+}
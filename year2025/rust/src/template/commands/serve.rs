@@ -0,0 +1,151 @@
+use std::{
+    io::{self, BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
+    process,
+};
+
+use crate::template::dashboard::Dashboard;
+
+pub const DEFAULT_PORT: u16 = 8025;
+
+struct Response {
+    status: &'static str,
+    content_type: &'static str,
+    body: String,
+}
+
+/// Data is re-read on every request so the page reflects the latest `time --store`.
+fn route(method: &str, path: &str) -> Response {
+    if method != "GET" {
+        return Response {
+            status: "405 Method Not Allowed",
+            content_type: "text/plain; charset=utf-8",
+            body: "method not allowed".into(),
+        };
+    }
+
+    match path.split('?').next().unwrap_or_default() {
+        "/" | "/index.html" => Response {
+            status: "200 OK",
+            content_type: "text/html; charset=utf-8",
+            body: Dashboard::load().to_html(),
+        },
+        "/api/dashboard.json" => Response {
+            status: "200 OK",
+            content_type: "application/json",
+            body: Dashboard::load().to_json().stringify().unwrap_or_default(),
+        },
+        _ => Response {
+            status: "404 Not Found",
+            content_type: "text/plain; charset=utf-8",
+            body: "not found".into(),
+        },
+    }
+}
+
+fn handle_connection(mut stream: TcpStream) -> io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+
+    // drain the request headers, the body of a GET request is ignored.
+    let mut header = String::new();
+    while reader.read_line(&mut header)? > 2 {
+        header.clear();
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let response = match (parts.next(), parts.next()) {
+        (Some(method), Some(path)) => route(method, path),
+        _ => Response {
+            status: "400 Bad Request",
+            content_type: "text/plain; charset=utf-8",
+            body: "bad request".into(),
+        },
+    };
+
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        response.content_type,
+        response.body.len(),
+        response.body
+    )?;
+    stream.flush()
+}
+
+/// Serve requests one by one until the process is stopped.
+pub fn serve(listener: &TcpListener) {
+    for stream in listener.incoming() {
+        match stream.and_then(handle_connection) {
+            Ok(()) => {}
+            Err(e) => eprintln!("Failed to handle request: {e}"),
+        }
+    }
+}
+
+pub fn handle(port: u16) {
+    let listener = match TcpListener::bind(("127.0.0.1", port)) {
+        Ok(listener) => listener,
+        Err(e) => {
+            eprintln!("Failed to bind to port {port}: {e}");
+            process::exit(1);
+        }
+    };
+
+    println!("🎄 Serving dashboard on http://127.0.0.1:{port}/");
+    println!("🎄 JSON data is available at http://127.0.0.1:{port}/api/dashboard.json");
+    serve(&listener);
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::handle_connection;
+    use std::{
+        io::{Read, Write},
+        net::{TcpListener, TcpStream},
+        thread,
+    };
+
+    fn request(request: &str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            handle_connection(stream).unwrap();
+        });
+
+        let mut client = TcpStream::connect(address).unwrap();
+        client.write_all(request.as_bytes()).unwrap();
+        let mut response = String::new();
+        client.read_to_string(&mut response).unwrap();
+
+        server.join().unwrap();
+        response
+    }
+
+    #[test]
+    fn serves_html_page() {
+        let response = request("GET / HTTP/1.1\r\nHost: localhost\r\n\r\n");
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("Content-Type: text/html"));
+        assert!(response.contains("<h1>Advent of Code</h1>"));
+    }
+
+    #[test]
+    fn serves_json_endpoint() {
+        let response = request("GET /api/dashboard.json HTTP/1.1\r\n\r\n");
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("Content-Type: application/json"));
+        assert!(response.contains("\"days\":"));
+    }
+
+    #[test]
+    fn rejects_unknown_paths() {
+        let response = request("GET /missing HTTP/1.1\r\n\r\n");
+        assert!(response.starts_with("HTTP/1.1 404 Not Found\r\n"));
+    }
+}
@@ -0,0 +1,28 @@
+use std::process::{self, Command, Stdio};
+
+use crate::template::Day;
+use crate::template::snapshot::UPDATE_ENV;
+
+/// Run the tests of one or all days, optionally writing the snapshots they assert.
+pub fn handle(day: Option<Day>, update: bool) {
+    let mut cmd_args = vec!["test".to_string()];
+
+    match day {
+        Some(day) => cmd_args.extend(["--bin".to_string(), day.to_string()]),
+        None => cmd_args.push("--bins".to_string()),
+    }
+
+    let mut cmd = Command::new("cargo");
+    cmd.args(&cmd_args)
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit());
+
+    if update {
+        cmd.env(UPDATE_ENV, "1");
+    }
+
+    let status = cmd.status().unwrap();
+    if !status.success() {
+        process::exit(status.code().unwrap_or(1));
+    }
+}
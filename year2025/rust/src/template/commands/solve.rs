@@ -1,8 +1,58 @@
-use std::process::{Command, Stdio};
+use std::process::{self, Command, Stdio};
 
 use crate::template::Day;
+use crate::template::run_multi::check_fresh_processes;
+
+/// Run the example tests of a day, returns whether all of them passed.
+fn examples_pass(day: Day, release: bool) -> bool {
+    let day_padded = day.to_string();
+    let mut cmd_args = vec!["test", "--quiet", "--bin", &day_padded];
+
+    if release {
+        cmd_args.push("--release");
+    }
+
+    Command::new("cargo")
+        .args(&cmd_args)
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    day: Day,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<u8>,
+    force: bool,
+    ocr: bool,
+    repeat: usize,
+    verbose: bool,
+) {
+    // The solution refuses to submit from a debug build, so don't spend time on the examples first.
+    // The dhat profile inherits from release, so it builds without debug assertions.
+    if let Some(part) = submit_part
+        && !release
+        && !dhat
+    {
+        eprintln!(
+            "Refusing to submit from a debug build. Format: cargo solve {day} --release --submit {part}"
+        );
+        process::exit(1);
+    }
+
+    if submit_part.is_some() && !force {
+        println!("Checking examples before submitting...");
+        if !examples_pass(day, release) {
+            eprintln!(
+                "Refusing to submit: the examples of day {day} are failing. Pass `--force` to submit anyway."
+            );
+            process::exit(1);
+        }
+    }
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
     let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
 
     if dhat {
@@ -18,6 +68,19 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
 
     cmd_args.push("--".to_string());
 
+    if ocr {
+        cmd_args.push("--ocr".to_string());
+    }
+
+    if verbose {
+        cmd_args.push("--verbose".to_string());
+    }
+
+    if repeat > 1 {
+        cmd_args.push("--repeat".to_string());
+        cmd_args.push(repeat.to_string());
+    }
+
     if let Some(submit_part) = submit_part {
         cmd_args.push("--submit".to_string());
         cmd_args.push(submit_part.to_string());
@@ -31,4 +94,8 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         .unwrap();
 
     cmd.wait().unwrap();
+
+    if repeat > 1 && !check_fresh_processes(day, release, repeat) {
+        process::exit(1);
+    }
 }
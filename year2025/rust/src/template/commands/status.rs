@@ -0,0 +1,129 @@
+use std::{fs, path::Path};
+
+use crate::template::answers::Answers;
+use crate::template::timings::Timings;
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day, all_days, puzzles};
+
+/// Snapshot of everything we know locally about a single day.
+#[derive(Clone, Debug, Default)]
+pub struct DayStatus {
+    pub solution: bool,
+    pub input: bool,
+    pub example: bool,
+    pub puzzle: bool,
+    pub timed: [bool; 2],
+    pub stars: u8,
+}
+
+impl DayStatus {
+    fn collect(day: Day, timings: &Timings, answers: &Answers) -> Self {
+        let timing = timings.data.iter().find(|t| t.day == day);
+
+        DayStatus {
+            solution: Path::new(&format!("src/bin/{day}.rs")).exists(),
+            input: has_content(&format!("data/inputs/{day}.txt")),
+            example: has_content(&format!("data/examples/{day}.txt")),
+            puzzle: has_content(&format!("data/puzzles/{day}.md")),
+            timed: [
+                timing.is_some_and(|t| t.part_1.is_some()),
+                timing.is_some_and(|t| t.part_2.is_some()),
+            ],
+            // answers submitted through the website only show up in the puzzle description.
+            stars: puzzles::stars(day, answers, puzzles::read(day).as_deref()),
+        }
+    }
+}
+
+/// `scaffold` creates empty input and example files, so only count files with content.
+fn has_content(path: &str) -> bool {
+    fs::metadata(path).is_ok_and(|m| m.len() > 0)
+}
+
+fn mark(present: bool) -> &'static str {
+    if present { "✔" } else { "·" }
+}
+
+fn render(days: &[(Day, DayStatus)]) -> String {
+    let mut lines: Vec<String> = vec![
+        format!("{ANSI_BOLD}Day  Solution  Input  Example  Puzzle  Bench  Stars{ANSI_RESET}"),
+        "---  --------  -----  -------  ------  -----  -----".into(),
+    ];
+
+    for (day, status) in days {
+        let day = day.to_string();
+        let bench = format!(
+            "{} {}",
+            if status.timed[0] { "1" } else { "·" },
+            if status.timed[1] { "2" } else { "·" }
+        );
+        let stars = format!(
+            "{}{}",
+            "★".repeat(status.stars.into()),
+            "☆".repeat(2 - usize::from(status.stars.min(2)))
+        );
+        lines.push(format!(
+            "{day:<3}  {:<8}  {:<5}  {:<7}  {:<6}  {bench:<5}  {stars}",
+            mark(status.solution),
+            mark(status.input),
+            mark(status.example),
+            mark(status.puzzle),
+        ));
+    }
+
+    let count = |f: fn(&DayStatus) -> bool| days.iter().filter(|(_, s)| f(s)).count();
+    let stars: u32 = days.iter().map(|(_, s)| u32::from(s.stars)).sum();
+
+    lines.push(String::new());
+    lines.push(format!(
+        "{ANSI_BOLD}Stars:{ANSI_RESET} {stars}/{} {ANSI_ITALIC}(scaffolded {}, inputs {}, benched {}){ANSI_RESET}",
+        days.len() * 2,
+        count(|s| s.solution),
+        count(|s| s.input),
+        count(|s| s.timed[0] && s.timed[1]),
+    ));
+
+    lines.join("\n")
+}
+
+pub fn handle() {
+    let timings = Timings::read_from_file();
+    let answers = Answers::read_from_file();
+
+    let days: Vec<(Day, DayStatus)> = all_days()
+        .map(|day| (day, DayStatus::collect(day, &timings, &answers)))
+        .collect();
+
+    println!("{}", render(&days));
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{DayStatus, render};
+    use crate::day;
+
+    #[test]
+    fn renders_day_rows() {
+        let days = vec![
+            (
+                day!(1),
+                DayStatus {
+                    solution: true,
+                    input: true,
+                    example: true,
+                    puzzle: true,
+                    timed: [true, true],
+                    stars: 2,
+                },
+            ),
+            (day!(2), DayStatus::default()),
+        ];
+
+        let output = render(&days);
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines[2], "01   ✔         ✔      ✔        ✔       1 2    ★★");
+        assert_eq!(lines[3], "02   ·         ·      ·        ·       · ·    ☆☆");
+        assert!(lines[5].contains("2/4"));
+        assert!(lines[5].contains("scaffolded 1, inputs 1, benched 1"));
+    }
+}
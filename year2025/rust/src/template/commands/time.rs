@@ -1,5 +1,6 @@
 use std::collections::HashSet;
 
+use crate::template::history::History;
 use crate::template::run_multi::run_multi;
 use crate::template::timings::Timings;
 use crate::template::{Day, all_days, readme_benchmarks};
@@ -21,12 +22,16 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let timings = run_multi(&days_to_run, true, true, 1, false).unwrap();
 
     if store {
         let merged_timings = stored_timings.merge(&timings);
         merged_timings.store_file().unwrap();
 
+        let mut history = History::read_from_file();
+        history.append(&timings);
+        history.store_file().unwrap();
+
         println!();
         match readme_benchmarks::update(merged_timings) {
             Ok(()) => {
@@ -0,0 +1,217 @@
+use std::{
+    io::{Write, stdout},
+    process, thread,
+    time::Duration,
+};
+
+use chrono::{DateTime, Datelike, Utc};
+
+use crate::template::commands::{download, read, scaffold};
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, all_days, aoc_cli, format_clock};
+
+/// Number of download attempts after a puzzle unlocks. Early requests may still see a 404.
+const DOWNLOAD_ATTEMPTS: u32 = 6;
+const DOWNLOAD_BACKOFF: Duration = Duration::from_secs(2);
+const MAX_DOWNLOAD_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Source of the current time. Injectable so unlock logic can be tested without waiting for midnight.
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+    fn sleep(&self, duration: Duration);
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+/// Returns the next puzzle to unlock after `now`, together with its unlock instant.
+pub fn next_unlock(now: DateTime<Utc>, year: i32) -> Option<(Day, DateTime<Utc>)> {
+    all_days()
+        .filter_map(|day| Some((day, day.unlock_time(year)?)))
+        .find(|(_, unlock)| *unlock > now)
+}
+
+/// Block until `unlock`, printing a countdown once per second.
+pub fn wait_for_unlock(clock: &impl Clock, day: Day, unlock: DateTime<Utc>) {
+    let mut stdout = stdout();
+
+    while let Ok(remaining) = (unlock - clock.now()).to_std() {
+        if remaining.is_zero() {
+            break;
+        }
+        print!(
+            "\r⏳ Day {day} unlocks in {ANSI_BOLD}{}{ANSI_RESET} ",
+            format_clock(remaining.as_secs())
+        );
+        let _ = stdout.flush();
+        clock.sleep(remaining.min(Duration::from_secs(1)));
+    }
+
+    println!("\r🎄 Day {day} is unlocked!                    ");
+}
+
+/// Call `f` until it succeeds, doubling the delay between attempts.
+pub fn retry<T, E>(
+    clock: &impl Clock,
+    attempts: u32,
+    mut f: impl FnMut() -> Result<T, E>,
+) -> Result<T, E> {
+    let mut delay = DOWNLOAD_BACKOFF;
+    let mut attempt = 1;
+    loop {
+        match f() {
+            Ok(value) => return Ok(value),
+            Err(e) if attempt >= attempts => return Err(e),
+            Err(_) => {
+                eprintln!(
+                    "Attempt {attempt} failed, retrying in {}s...",
+                    delay.as_secs()
+                );
+                clock.sleep(delay);
+                delay = (delay * 2).min(MAX_DOWNLOAD_BACKOFF);
+                attempt += 1;
+            }
+        }
+    }
+}
+
+fn wait_and_fetch(clock: &impl Clock) -> Day {
+    let now = clock.now();
+    let year = aoc_cli::get_year().map_or(now.year(), i32::from);
+
+    let Some((day, unlock)) = next_unlock(now, year) else {
+        eprintln!("All puzzles of {year} are already unlocked.");
+        process::exit(1);
+    };
+
+    wait_for_unlock(clock, day, unlock);
+
+    if let Err(e) = retry(clock, DOWNLOAD_ATTEMPTS, || download::fetch(day)) {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+
+    scaffold::handle(day, false, &scaffold::Options::default());
+    day
+}
+
+pub fn handle(wait: bool) {
+    let day = if wait {
+        wait_and_fetch(&SystemClock)
+    } else {
+        match Day::today() {
+            Some(day) => {
+                download::handle(day);
+                scaffold::handle(day, false, &scaffold::Options::default());
+                day
+            }
+            None => {
+                eprintln!(
+                    "`today` command can only be run between the 1st and \
+                    the 25th of december. Please use `scaffold` with a specific day."
+                );
+                process::exit(1)
+            }
+        }
+    };
+
+    read::handle(day);
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Clock, next_unlock, retry, wait_for_unlock};
+    use crate::day;
+    use chrono::{DateTime, TimeZone, Utc};
+    use std::{cell::Cell, time::Duration};
+
+    /// A clock that only advances when slept on.
+    struct FakeClock {
+        now: Cell<DateTime<Utc>>,
+        slept: Cell<Duration>,
+    }
+
+    impl FakeClock {
+        fn at(now: DateTime<Utc>) -> Self {
+            FakeClock {
+                now: Cell::new(now),
+                slept: Cell::new(Duration::ZERO),
+            }
+        }
+    }
+
+    impl Clock for FakeClock {
+        fn now(&self) -> DateTime<Utc> {
+            self.now.get()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            self.now
+                .set(self.now.get() + chrono::Duration::from_std(duration).unwrap());
+            self.slept.set(self.slept.get() + duration);
+        }
+    }
+
+    #[test]
+    fn unlocks_at_midnight_server_time() {
+        let unlock = day!(1).unlock_time(2025).unwrap();
+        assert_eq!(unlock, Utc.with_ymd_and_hms(2025, 12, 1, 5, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn finds_next_unlock() {
+        let before_event = Utc.with_ymd_and_hms(2025, 11, 20, 12, 0, 0).unwrap();
+        assert_eq!(next_unlock(before_event, 2025).unwrap().0, day!(1));
+
+        // 23:59 server time on the 6th.
+        let during_event = Utc.with_ymd_and_hms(2025, 12, 7, 4, 59, 0).unwrap();
+        let (day, unlock) = next_unlock(during_event, 2025).unwrap();
+        assert_eq!(day, day!(7));
+        assert_eq!(unlock, Utc.with_ymd_and_hms(2025, 12, 7, 5, 0, 0).unwrap());
+
+        let after_event = Utc.with_ymd_and_hms(2025, 12, 26, 12, 0, 0).unwrap();
+        assert!(next_unlock(after_event, 2025).is_none());
+    }
+
+    #[test]
+    fn finds_today() {
+        let now = Utc.with_ymd_and_hms(2025, 12, 7, 4, 59, 0).unwrap();
+        assert_eq!(crate::template::Day::today_at(now), Some(day!(6)));
+    }
+
+    #[test]
+    fn waits_until_unlock() {
+        let clock = FakeClock::at(Utc.with_ymd_and_hms(2025, 12, 7, 4, 59, 30).unwrap());
+        let unlock = day!(7).unlock_time(2025).unwrap();
+        wait_for_unlock(&clock, day!(7), unlock);
+        assert_eq!(clock.now(), unlock);
+        assert_eq!(clock.slept.get(), Duration::from_secs(30));
+    }
+
+    #[test]
+    fn retries_with_backoff() {
+        let clock = FakeClock::at(Utc.with_ymd_and_hms(2025, 12, 7, 5, 0, 0).unwrap());
+        let mut calls = 0;
+        let result: Result<u32, &str> = retry(&clock, 5, || {
+            calls += 1;
+            if calls < 4 { Err("404") } else { Ok(calls) }
+        });
+        assert_eq!(result, Ok(4));
+        assert_eq!(clock.slept.get(), Duration::from_secs(2 + 4 + 8));
+    }
+
+    #[test]
+    fn gives_up_after_attempts() {
+        let clock = FakeClock::at(Utc.with_ymd_and_hms(2025, 12, 7, 5, 0, 0).unwrap());
+        let result: Result<(), &str> = retry(&clock, 2, || Err("404"));
+        assert_eq!(result, Err("404"));
+    }
+}
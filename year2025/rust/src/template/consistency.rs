@@ -0,0 +1,144 @@
+//! Detection of solutions whose answers differ between runs, e.g. because they iterate a `HashMap` with ties.
+
+/// Distinct answers of repeated runs of a part, in the order they were first seen.
+#[derive(Debug, Default)]
+pub struct Tally {
+    values: Vec<(String, usize)>,
+}
+
+impl Tally {
+    pub fn add(&mut self, value: String) {
+        match self.values.iter_mut().find(|(v, _)| *v == value) {
+            Some((_, count)) => *count += 1,
+            None => self.values.push((value, 1)),
+        }
+    }
+
+    /// Number of answers added so far.
+    pub fn runs(&self) -> usize {
+        self.values.iter().map(|(_, count)| count).sum()
+    }
+
+    pub fn is_consistent(&self) -> bool {
+        self.values.len() <= 1
+    }
+
+    /// Describe the differing answers, e.g. `2 distinct answers in 5 runs` followed by one line per answer.
+    pub fn summary(&self) -> String {
+        let mut lines = vec![format!(
+            "{} distinct answers in {} runs:",
+            self.values.len(),
+            self.runs()
+        )];
+        for (value, count) in &self.values {
+            let value = value.replace('\n', "\n         ");
+            lines.push(format!("  {count:>4}x ▸ {value}"));
+        }
+        lines.join("\n")
+    }
+}
+
+/// Read the answer of each part from the output of a solution binary.
+/// Durations, phases and resource usage are ignored, multiline answers include their lines.
+pub fn parse_answers(output: &[String]) -> Vec<(u8, String)> {
+    let mut answers: Vec<(u8, String)> = vec![];
+    let mut in_multiline = false;
+
+    for line in output {
+        // only keep what was printed after the last carriage return, intermediate output is overwritten.
+        let line = strip_ansi(line.rsplit('\r').next().unwrap_or_default());
+
+        let part = if line.starts_with("Part 1: ") {
+            Some(1)
+        } else if line.starts_with("Part 2: ") {
+            Some(2)
+        } else {
+            None
+        };
+
+        if let Some(part) = part {
+            let answer = line["Part 1: ".len()..].trim_end();
+            // strip the duration suffix, e.g. ` (1.2ms @ 10 samples)`.
+            let answer = match answer.rfind(" (") {
+                Some(index) if answer.ends_with(')') => answer[..index].trim_end(),
+                _ => answer,
+            };
+            in_multiline = answer == "▼";
+            answers.push((
+                part,
+                if in_multiline {
+                    String::new()
+                } else {
+                    answer.to_string()
+                },
+            ));
+        } else if line.starts_with("Resources: ") || line.trim_start().starts_with("↳ ") {
+            in_multiline = false;
+        } else if in_multiline && let Some((_, answer)) = answers.last_mut() {
+            if !answer.is_empty() {
+                answer.push('\n');
+            }
+            answer.push_str(&line);
+        }
+    }
+
+    answers
+}
+
+fn strip_ansi(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // skip the control sequence up to its final letter.
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Tally, parse_answers};
+
+    #[test]
+    fn tallies_answers() {
+        let mut tally = Tally::default();
+        tally.add("42".into());
+        tally.add("42".into());
+        assert!(tally.is_consistent());
+
+        tally.add("43".into());
+        assert!(!tally.is_consistent());
+        assert_eq!(tally.runs(), 3);
+        assert_eq!(
+            tally.summary(),
+            "2 distinct answers in 3 runs:\n     2x ▸ 42\n     1x ▸ 43"
+        );
+    }
+
+    #[test]
+    fn parses_answers_from_output() {
+        let output: Vec<String> = [
+            "Part 1: \x1b[1m42\x1b[0m > \x1b[3mbenching\x1b[0m\rPart 1: \x1b[1m42\x1b[0m (1.0ms @ 10 samples)",
+            "  ↳ parse (200.0µs)",
+            "Part 2: ▼  (2.0ms)",
+            "#..#",
+            ".##.",
+            "\x1b[3mResources: 1.0ms user, 0.5ms sys\x1b[0m",
+        ]
+        .map(String::from)
+        .to_vec();
+
+        assert_eq!(
+            parse_answers(&output),
+            [(1, "42".to_string()), (2, "#..#\n.##.".to_string())]
+        );
+    }
+}
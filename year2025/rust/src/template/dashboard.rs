@@ -0,0 +1,277 @@
+/// Renders the stored timings, answers and benchmark history as an HTML page or JSON document.
+use std::{collections::HashMap, fmt::Write};
+use tinyjson::JsonValue;
+
+use crate::template::answers::Answers;
+use crate::template::history::History;
+use crate::template::report::{bar_chart, escape};
+use crate::template::timings::Timings;
+use crate::template::{Day, all_days, puzzles};
+
+const SPARKLINE_WIDTH: f64 = 120.0;
+const SPARKLINE_HEIGHT: f64 = 18.0;
+
+pub struct Dashboard {
+    pub timings: Timings,
+    pub answers: Answers,
+    pub history: History,
+    /// Stored puzzle descriptions, which list the parts solved on the website.
+    pub puzzles: HashMap<Day, String>,
+}
+
+impl Dashboard {
+    /// Read all dashboard data from the `data` directory.
+    pub fn load() -> Self {
+        Dashboard {
+            timings: Timings::read_from_file(),
+            answers: Answers::read_from_file(),
+            history: History::read_from_file(),
+            puzzles: all_days()
+                .filter_map(|day| Some((day, puzzles::read(day)?)))
+                .collect(),
+        }
+    }
+
+    /// Stars of a day, counted like `status` does.
+    fn stars(&self, day: Day) -> u8 {
+        puzzles::stars(
+            day,
+            &self.answers,
+            self.puzzles.get(&day).map(String::as_str),
+        )
+    }
+
+    fn total_stars(&self) -> u32 {
+        all_days().map(|day| u32::from(self.stars(day))).sum()
+    }
+
+    /// Days that have either timings or answers, in order.
+    fn days(&self) -> Vec<Day> {
+        all_days()
+            .filter(|day| self.timings.data.iter().any(|t| t.day == *day) || self.stars(*day) > 0)
+            .collect()
+    }
+
+    fn total_nanos(&self, day: Day) -> f64 {
+        self.timings
+            .data
+            .iter()
+            .find(|t| t.day == day)
+            .map_or(0_f64, |t| t.total_nanos)
+    }
+
+    pub fn to_json(&self) -> JsonValue {
+        let days = self
+            .days()
+            .into_iter()
+            .map(|day| {
+                let timing = self.timings.data.iter().find(|t| t.day == day);
+                let optional = |v: Option<&str>| {
+                    v.map_or(JsonValue::Null, |v| JsonValue::String(v.to_string()))
+                };
+
+                let mut map: HashMap<String, JsonValue> = HashMap::new();
+                map.insert("day".into(), JsonValue::String(day.to_string()));
+                map.insert(
+                    "part_1".into(),
+                    optional(timing.and_then(|t| t.part_1.as_deref())),
+                );
+                map.insert(
+                    "part_2".into(),
+                    optional(timing.and_then(|t| t.part_2.as_deref())),
+                );
+                map.insert(
+                    "total_nanos".into(),
+                    JsonValue::Number(self.total_nanos(day)),
+                );
+                map.insert(
+                    "stars".into(),
+                    JsonValue::Number(f64::from(self.stars(day))),
+                );
+                map.insert(
+                    "history".into(),
+                    JsonValue::Array(self.history.for_day(day).map(JsonValue::from).collect()),
+                );
+                JsonValue::Object(map)
+            })
+            .collect();
+
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+        map.insert("days".into(), JsonValue::Array(days));
+        map.insert(
+            "total_millis".into(),
+            JsonValue::Number(self.timings.total_millis()),
+        );
+        map.insert(
+            "stars".into(),
+            JsonValue::Number(f64::from(self.total_stars())),
+        );
+        JsonValue::Object(map)
+    }
+
+    pub fn to_html(&self) -> String {
+        let days = self.days();
+
+        let mut html = String::new();
+        html.push_str(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+             <meta http-equiv=\"refresh\" content=\"30\">\n\
+             <title>Advent of Code</title>\n\
+             <style>\n\
+             body { font-family: monospace; background: #0f0f23; color: #cccccc; margin: 2em; }\n\
+             h1, .star { color: #ffff66; }\n\
+             table { border-collapse: collapse; }\n\
+             td, th { padding: 0.2em 1em; text-align: left; }\n\
+             rect { fill: #009900; }\n\
+             polyline { fill: none; stroke: #009900; stroke-width: 1.5; }\n\
+             text { fill: #cccccc; font-family: monospace; font-size: 12px; }\n\
+             </style>\n</head>\n<body>\n",
+        );
+
+        let _ = writeln!(html, "<h1>Advent of Code</h1>");
+        let _ = writeln!(
+            html,
+            "<p><span class=\"star\">{} ★</span> &middot; total runtime {:.2}ms</p>",
+            self.total_stars(),
+            self.timings.total_millis()
+        );
+
+        // runtime per day as a horizontal bar chart.
+        let bars: Vec<(String, f64)> = days
+            .iter()
+            .map(|day| (format!("Day {day}"), self.total_nanos(*day)))
+            .collect();
+        let _ = writeln!(html, "<h2>Runtime per day</h2>");
+        html.push_str(&bar_chart(&bars));
+
+        let _ = writeln!(
+            html,
+            "<h2>Days</h2>\n<table>\n<tr><th>Day</th><th>Part 1</th><th>Part 2</th><th>Stars</th><th>History</th></tr>"
+        );
+        for day in &days {
+            let timing = self.timings.data.iter().find(|t| t.day == *day);
+            let part = |v: Option<&String>| v.map_or_else(|| "-".into(), |v| escape(v));
+            let _ = writeln!(
+                html,
+                "<tr><td>{day}</td><td>{}</td><td>{}</td><td class=\"star\">{}</td><td>{}</td></tr>",
+                part(timing.and_then(|t| t.part_1.as_ref())),
+                part(timing.and_then(|t| t.part_2.as_ref())),
+                "★".repeat(self.stars(*day).into()),
+                self.sparkline(*day)
+            );
+        }
+        let _ = writeln!(html, "</table>");
+
+        html.push_str("</body>\n</html>\n");
+        html
+    }
+
+    /// Small inline chart of the stored benchmark runs of a day.
+    fn sparkline(&self, day: Day) -> String {
+        let runs: Vec<f64> = self.history.for_day(day).map(|e| e.total_nanos).collect();
+        if runs.len() < 2 {
+            return String::new();
+        }
+
+        let max = runs.iter().copied().fold(0_f64, f64::max);
+        #[allow(clippy::cast_precision_loss)]
+        let step = SPARKLINE_WIDTH / (runs.len() - 1) as f64;
+        let points: Vec<String> = runs
+            .iter()
+            .enumerate()
+            .map(|(index, nanos)| {
+                let y = if max > 0_f64 {
+                    SPARKLINE_HEIGHT - nanos / max * SPARKLINE_HEIGHT
+                } else {
+                    SPARKLINE_HEIGHT
+                };
+                #[allow(clippy::cast_precision_loss)]
+                let x = step * index as f64;
+                format!("{x:.1},{y:.1}")
+            })
+            .collect();
+
+        format!(
+            "<svg width=\"{SPARKLINE_WIDTH}\" height=\"{SPARKLINE_HEIGHT}\"><polyline points=\"{}\"></polyline></svg>",
+            points.join(" ")
+        )
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Dashboard;
+    use crate::{
+        day,
+        template::answers::Answers,
+        template::history::{History, HistoryEntry},
+        template::timings::{Timing, Timings},
+    };
+    use std::collections::HashMap;
+    use tinyjson::JsonValue;
+
+    fn get_mock_dashboard() -> Dashboard {
+        let mut answers = Answers::default();
+        answers.record(day!(1), 1, "42");
+        answers.record(day!(1), 2, "43");
+
+        Dashboard {
+            timings: Timings {
+                data: vec![Timing {
+                    day: day!(1),
+                    part_1: Some("10ms".into()),
+                    part_2: Some("20ms".into()),
+                    total_nanos: 3e+7,
+                    phases: vec![],
+                    resources: None,
+                }],
+            },
+            answers,
+            history: History {
+                data: vec![
+                    HistoryEntry {
+                        day: day!(1),
+                        timestamp: 1,
+                        total_nanos: 4e+7,
+                    },
+                    HistoryEntry {
+                        day: day!(1),
+                        timestamp: 2,
+                        total_nanos: 3e+7,
+                    },
+                ],
+            },
+            puzzles: HashMap::from([(
+                day!(2),
+                "--- Day 2 ---\n\nYour puzzle answer was `7`.\n".to_string(),
+            )]),
+        }
+    }
+
+    #[test]
+    fn renders_html() {
+        let html = get_mock_dashboard().to_html();
+        assert!(html.contains("<td>01</td><td>10ms</td><td>20ms</td>"));
+        assert!(html.contains("30.00ms"));
+        assert!(html.contains("<polyline"));
+    }
+
+    #[test]
+    fn renders_json() {
+        let json = get_mock_dashboard().to_json();
+        let map = json.get::<HashMap<String, JsonValue>>().unwrap();
+        // day 2 was solved on the website, so its star only shows in the puzzle description.
+        assert_eq!(map.get("stars").unwrap().get::<f64>(), Some(&3_f64));
+
+        let days = map.get("days").unwrap().get::<Vec<JsonValue>>().unwrap();
+        assert_eq!(days.len(), 2);
+        let history = days[0]
+            .get::<HashMap<String, JsonValue>>()
+            .unwrap()
+            .get("history")
+            .unwrap()
+            .get::<Vec<JsonValue>>()
+            .unwrap();
+        assert_eq!(history.len(), 2);
+    }
+}
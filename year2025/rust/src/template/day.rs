@@ -3,7 +3,7 @@ use std::fmt::Display;
 use std::str::FromStr;
 
 #[cfg(feature = "today")]
-use chrono::{Datelike, FixedOffset, Utc};
+use chrono::{DateTime, Datelike, FixedOffset, TimeZone, Utc};
 
 #[cfg(feature = "today")]
 const SERVER_UTC_OFFSET: i32 = -5;
@@ -41,14 +41,28 @@ impl Day {
 impl Day {
     /// Returns the current day if it's between the 1st and the 25th of december, `None` otherwise.
     pub fn today() -> Option<Self> {
+        Self::today_at(Utc::now())
+    }
+
+    /// Returns the day of advent at the instant `now`, see [`Day::today`].
+    pub fn today_at(now: DateTime<Utc>) -> Option<Self> {
         let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
-        let today = Utc::now().with_timezone(&offset);
+        let today = now.with_timezone(&offset);
         if today.month() == 12 && today.day() <= 25 {
             Self::new(u8::try_from(today.day()).ok()?)
         } else {
             None
         }
     }
+
+    /// Returns the instant at which the puzzle of this day unlocks in `year` (midnight server time).
+    pub fn unlock_time(self, year: i32) -> Option<DateTime<Utc>> {
+        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
+        offset
+            .with_ymd_and_hms(year, 12, u32::from(self.0), 0, 0, 0)
+            .single()
+            .map(|time| time.with_timezone(&Utc))
+    }
 }
 
 impl Display for Day {
@@ -0,0 +1,207 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::Error,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::Day;
+use crate::template::timings::Timings;
+
+static HISTORY_FILE_PATH: &str = "./data/history.json";
+
+/// A single stored benchmark run of one day.
+#[derive(Clone, Debug)]
+pub struct HistoryEntry {
+    pub day: Day,
+    /// Seconds since the unix epoch at which the run was stored.
+    pub timestamp: u64,
+    pub total_nanos: f64,
+}
+
+/// Every benchmark run stored via `time --store`, oldest first.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct History {
+    pub data: Vec<HistoryEntry>,
+}
+
+impl History {
+    /// Dehydrate history to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(HISTORY_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate history from a JSON file. If not present, returns an empty history.
+    pub fn read_from_file() -> Self {
+        fs::read_to_string(HISTORY_FILE_PATH)
+            .map_err(|x| x.to_string())
+            .and_then(History::try_from)
+            .unwrap_or_default()
+    }
+
+    /// Append a benchmark run for every day that has timings.
+    pub fn append(&mut self, timings: &Timings) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+
+        for timing in &timings.data {
+            if timing.part_1.is_none() && timing.part_2.is_none() {
+                continue;
+            }
+            self.data.push(HistoryEntry {
+                day: timing.day,
+                timestamp,
+                total_nanos: timing.total_nanos,
+            });
+        }
+    }
+
+    /// All stored runs of a day, oldest first.
+    pub fn for_day(&self, day: Day) -> impl Iterator<Item = &HistoryEntry> {
+        self.data.iter().filter(move |e| e.day == day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<History> for JsonValue {
+    fn from(value: History) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for History {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(History {
+            data: json_data
+                .iter()
+                .map(HistoryEntry::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&HistoryEntry> for JsonValue {
+    fn from(value: &HistoryEntry) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for HistoryEntry {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected history entry to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected entry.day to be a Day struct.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>().copied())
+            .map(|v| v as u64)
+            .ok_or("Expected entry.timestamp to be a number.")?;
+
+        let total_nanos = json
+            .get("total_nanos")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected entry.total_nanos to be a number.")?;
+
+        Ok(HistoryEntry {
+            day,
+            timestamp,
+            total_nanos,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use crate::{
+        day,
+        template::history::History,
+        template::timings::{Timing, Timings},
+    };
+
+    #[test]
+    fn handles_json_history() {
+        let json = r#"{ "data": [{ "day": "01", "timestamp": 1700000000, "total_nanos": 2000 }, { "day": "01", "timestamp": 1700000100, "total_nanos": 1000 }] }"#.to_string();
+        let history = History::try_from(json).unwrap();
+        let runs: Vec<_> = history.for_day(day!(1)).collect();
+        assert_eq!(runs.len(), 2);
+        assert_eq!(runs[1].timestamp, 1_700_000_100);
+        assert_eq!(runs[1].total_nanos, 1000_f64);
+    }
+
+    #[test]
+    fn appends_only_timed_days() {
+        let mut history = History::default();
+        history.append(&Timings {
+            data: vec![
+                Timing {
+                    day: day!(1),
+                    part_1: Some("1ms".into()),
+                    part_2: None,
+                    total_nanos: 1_000_000_f64,
+                    phases: vec![],
+                    resources: None,
+                },
+                Timing {
+                    day: day!(2),
+                    part_1: None,
+                    part_2: None,
+                    total_nanos: 0_f64,
+                    phases: vec![],
+                    resources: None,
+                },
+            ],
+        });
+        assert_eq!(history.data.len(), 1);
+        assert_eq!(history.data[0].day, day!(1));
+    }
+}
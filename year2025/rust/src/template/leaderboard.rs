@@ -0,0 +1,380 @@
+/// Parses and renders the JSON format of a private leaderboard.
+use std::{collections::HashMap, fmt::Write, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day, all_days, format_clock};
+
+/// Star timestamps (seconds since the unix epoch) of part one and two, indexed by day.
+type Completions = [[Option<u64>; 2]; 25];
+
+#[derive(Clone, Debug)]
+pub struct Member {
+    pub id: u64,
+    pub name: String,
+    pub stars: u32,
+    pub local_score: u32,
+    pub completions: Completions,
+}
+
+impl Member {
+    fn star_ts(&self, day: Day, part: usize) -> Option<u64> {
+        self.completions[usize::from(day.into_inner()) - 1][part]
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Leaderboard {
+    pub year: i32,
+    pub members: Vec<Member>,
+}
+
+/// Seconds since the unix epoch at which a puzzle unlocks (midnight UTC-5).
+fn unlock_ts(year: i32, day: Day) -> u64 {
+    // days since the epoch of december 1st, see http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    let y = i64::from(year);
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * (12 - 3) + 2) / 5;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468 + i64::from(day.into_inner()) - 1;
+    u64::try_from(days * 86_400 + 5 * 3600).unwrap_or_default()
+}
+
+impl Leaderboard {
+    /// Time from unlock to a star of a member.
+    fn solve_time(&self, member: &Member, day: Day, part: usize) -> Option<u64> {
+        member
+            .star_ts(day, part)
+            .map(|ts| ts.saturating_sub(unlock_ts(self.year, day)))
+    }
+
+    /// Time between part one and part two of a member.
+    fn delta(member: &Member, day: Day) -> Option<u64> {
+        Some(
+            member
+                .star_ts(day, 1)?
+                .saturating_sub(member.star_ts(day, 0)?),
+        )
+    }
+
+    /// Days on which at least one member earned a star.
+    fn days(&self) -> Vec<Day> {
+        all_days()
+            .filter(|day| self.members.iter().any(|m| m.star_ts(*day, 0).is_some()))
+            .collect()
+    }
+
+    /// Smallest time between part one and two across all members and days.
+    pub fn fastest_part_two_delta(&self) -> Option<(&Member, Day, u64)> {
+        self.members
+            .iter()
+            .flat_map(|m| all_days().filter_map(move |day| Some((m, day, Self::delta(m, day)?))))
+            .min_by_key(|(_, _, delta)| *delta)
+    }
+
+    /// Rank of every member after each day, in member order. Scores are accumulated with the
+    /// local scoring rules: the first to get a star receives one point per member, the next one less.
+    pub fn rank_history(&self) -> Vec<(Day, Vec<usize>)> {
+        let count = u32::try_from(self.members.len()).unwrap_or(u32::MAX);
+        let mut scores = vec![0_u32; self.members.len()];
+        let mut history = vec![];
+
+        for day in self.days() {
+            for part in 0..2 {
+                let mut finishers: Vec<(u64, usize)> = self
+                    .members
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(index, m)| Some((m.star_ts(day, part)?, index)))
+                    .collect();
+                finishers.sort_unstable();
+
+                for (points, (_, index)) in (1..=count).rev().zip(finishers) {
+                    scores[index] += points;
+                }
+            }
+
+            let mut order: Vec<usize> = (0..self.members.len()).collect();
+            order.sort_by_key(|index| std::cmp::Reverse(scores[*index]));
+
+            let mut ranks = vec![0; self.members.len()];
+            for (rank, index) in order.into_iter().enumerate() {
+                ranks[index] = rank + 1;
+            }
+            history.push((day, ranks));
+        }
+
+        history
+    }
+
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+
+        let mut members: Vec<&Member> = self.members.iter().collect();
+        members.sort_by_key(|m| std::cmp::Reverse(m.local_score));
+        let name_width = members
+            .iter()
+            .map(|m| m.name.chars().count())
+            .max()
+            .unwrap_or(4)
+            .max(4);
+
+        let _ = writeln!(
+            out,
+            "{ANSI_BOLD}Private leaderboard {}{ANSI_RESET}",
+            self.year
+        );
+        let _ = writeln!(out);
+        let _ = writeln!(
+            out,
+            "{ANSI_BOLD}{:>4}  {:<name_width$}  {:>5}  {:>5}  Days{ANSI_RESET}",
+            "#", "Name", "Score", "Stars"
+        );
+        for (rank, member) in members.iter().enumerate() {
+            let days: String = all_days()
+                .map(
+                    |day| match (member.star_ts(day, 0), member.star_ts(day, 1)) {
+                        (Some(_), Some(_)) => '★',
+                        (Some(_), None) => '☆',
+                        _ => '·',
+                    },
+                )
+                .collect();
+            let _ = writeln!(
+                out,
+                "{:>4}  {:<name_width$}  {:>5}  {:>5}  {days}",
+                rank + 1,
+                member.name,
+                member.local_score,
+                member.stars,
+            );
+        }
+
+        for day in self.days() {
+            let _ = writeln!(out);
+            let _ = writeln!(out, "{ANSI_BOLD}Day {day}{ANSI_RESET}");
+
+            let mut finishers: Vec<&Member> = members
+                .iter()
+                .copied()
+                .filter(|m| m.star_ts(day, 0).is_some())
+                .collect();
+            finishers.sort_by_key(|m| (m.star_ts(day, 1).unwrap_or(u64::MAX), m.star_ts(day, 0)));
+
+            for member in finishers {
+                let time = |part| {
+                    self.solve_time(member, day, part)
+                        .map_or_else(|| "-".into(), format_clock)
+                };
+                let delta = Self::delta(member, day).map_or_else(|| "-".into(), format_clock);
+                let _ = writeln!(
+                    out,
+                    "      {:<name_width$}  part 1 {:>11}  part 2 {:>11}  {ANSI_ITALIC}Δ {delta}{ANSI_RESET}",
+                    member.name,
+                    time(0),
+                    time(1),
+                );
+            }
+        }
+
+        let _ = writeln!(out);
+        let _ = writeln!(out, "{ANSI_BOLD}Stats{ANSI_RESET}");
+        if let Some((member, day, delta)) = self.fastest_part_two_delta() {
+            let _ = writeln!(
+                out,
+                "Fastest part 2: {} on day {day} ({})",
+                member.name,
+                format_clock(delta)
+            );
+        }
+
+        let history = self.rank_history();
+        if !history.is_empty() {
+            let _ = writeln!(out, "Rank history:");
+            for member in &members {
+                let index = self.members.iter().position(|m| m.id == member.id).unwrap();
+                let ranks: Vec<String> = history
+                    .iter()
+                    .map(|(_, ranks)| ranks[index].to_string())
+                    .collect();
+                let _ = writeln!(
+                    out,
+                    "      {:<name_width$}  {}",
+                    member.name,
+                    ranks.join(" → ")
+                );
+            }
+        }
+
+        out
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl TryFrom<String> for Leaderboard {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let year = json
+            .get("event")
+            .and_then(|v| v.get::<String>())
+            .and_then(|v| v.parse().ok())
+            .ok_or("expected `json.event` to be a year.")?;
+
+        let mut members = json
+            .get("members")
+            .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+            .ok_or("expected `json.members` to be an object.")?
+            .values()
+            .map(Member::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        members.sort_unstable_by_key(|m| m.id);
+
+        Ok(Leaderboard { year, members })
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn as_u64(value: &JsonValue) -> Option<u64> {
+    value.get::<f64>().map(|v| *v as u64)
+}
+
+impl TryFrom<&JsonValue> for Member {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected member to be a JSON object.")?;
+
+        let id = json
+            .get("id")
+            .and_then(as_u64)
+            .ok_or("Expected member.id to be a number.")?;
+
+        // anonymous members have a `null` name.
+        let name = json
+            .get("name")
+            .and_then(|v| v.get::<String>())
+            .cloned()
+            .unwrap_or_else(|| format!("(anonymous user #{id})"));
+
+        let count = |key: &str| {
+            json.get(key)
+                .and_then(as_u64)
+                .and_then(|v| u32::try_from(v).ok())
+                .ok_or(format!("Expected member.{key} to be a number."))
+        };
+
+        let mut completions: Completions = [[None; 2]; 25];
+        let days = json
+            .get("completion_day_level")
+            .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+            .ok_or("Expected member.completion_day_level to be an object.")?;
+        for (day, parts) in days {
+            let day = Day::from_str(day).map_err(|e| e.to_string())?;
+            let parts = parts
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or("Expected completion day to be an object.")?;
+            for (part, star) in parts {
+                let part = match part.as_str() {
+                    "1" => 0,
+                    "2" => 1,
+                    _ => return Err(format!("Unexpected part {part}.")),
+                };
+                completions[usize::from(day.into_inner()) - 1][part] = star
+                    .get::<HashMap<String, JsonValue>>()
+                    .and_then(|star| star.get("get_star_ts"))
+                    .and_then(as_u64);
+            }
+        }
+
+        Ok(Member {
+            id,
+            name,
+            stars: count("stars")?,
+            local_score: count("local_score")?,
+            completions,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Leaderboard, unlock_ts};
+    use crate::day;
+
+    // day 1 of 2025 unlocks at 1764565200.
+    const JSON: &str = r#"{
+        "event": "2025",
+        "owner_id": 1,
+        "members": {
+            "1": { "id": 1, "name": "alice", "stars": 3, "local_score": 9, "global_score": 0, "last_star_ts": 0,
+                   "completion_day_level": {
+                       "1": { "1": { "get_star_ts": 1764565500, "star_index": 0 }, "2": { "get_star_ts": 1764566100, "star_index": 1 } },
+                       "2": { "1": { "get_star_ts": 1764652000, "star_index": 2 } }
+                   } },
+            "2": { "id": 2, "name": null, "stars": 2, "local_score": 6, "global_score": 0, "last_star_ts": 0,
+                   "completion_day_level": {
+                       "1": { "1": { "get_star_ts": 1764565400, "star_index": 0 }, "2": { "get_star_ts": 1764565460, "star_index": 1 } }
+                   } },
+            "3": { "id": 3, "name": "carol", "stars": 0, "local_score": 0, "global_score": 0, "last_star_ts": 0,
+                   "completion_day_level": {} }
+        }
+    }"#;
+
+    fn leaderboard() -> Leaderboard {
+        Leaderboard::try_from(JSON.to_string()).unwrap()
+    }
+
+    #[test]
+    fn computes_unlock_timestamps() {
+        assert_eq!(unlock_ts(2025, day!(1)), 1_764_565_200);
+        assert_eq!(unlock_ts(2024, day!(25)), 1_735_102_800);
+    }
+
+    #[test]
+    fn parses_members() {
+        let leaderboard = leaderboard();
+        assert_eq!(leaderboard.year, 2025);
+        assert_eq!(leaderboard.members.len(), 3);
+        assert_eq!(leaderboard.members[1].name, "(anonymous user #2)");
+        assert_eq!(leaderboard.members[0].star_ts(day!(2), 1), None);
+    }
+
+    #[test]
+    fn finds_fastest_part_two() {
+        let leaderboard = leaderboard();
+        let (member, day, delta) = leaderboard.fastest_part_two_delta().unwrap();
+        assert_eq!(member.id, 2);
+        assert_eq!(day, day!(1));
+        assert_eq!(delta, 60);
+    }
+
+    #[test]
+    fn computes_rank_history() {
+        let history = leaderboard().rank_history();
+        assert_eq!(history.len(), 2);
+        // day 1: member 2 was faster on both parts.
+        assert_eq!(history[0].1, vec![2, 1, 3]);
+        // day 2: member 1 catches up with the only star.
+        assert_eq!(history[1].1, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn renders_leaderboard() {
+        let output = leaderboard().render();
+        assert!(output.contains("part 1    00:05:00  part 2    00:15:00"));
+        assert!(output.contains("Fastest part 2: (anonymous user #2) on day 01 (00:01:00)"));
+        assert!(output.contains("2 → 1"));
+    }
+}
@@ -2,12 +2,25 @@ use std::{env, fs};
 
 pub mod aoc_cli;
 pub mod commands;
+pub mod ocr;
+pub mod phase;
+pub mod report;
 pub mod runner;
+pub mod snapshot;
+pub mod trace;
 
 pub use day::*;
 
+mod answers;
+mod consistency;
+mod dashboard;
 mod day;
+mod history;
+mod leaderboard;
+mod puzzles;
 mod readme_benchmarks;
+mod remote;
+mod resources;
 mod run_multi;
 mod timings;
 
@@ -36,6 +49,21 @@ pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
     f.expect("could not open input file")
 }
 
+/// Formats a number of seconds as `hh:mm:ss`, prefixed with the number of days if there are any, e.g. `1d 01:01:01`.
+pub(crate) fn format_clock(secs: u64) -> String {
+    let (days, hours, minutes, seconds) = (
+        secs / 86_400,
+        secs % 86_400 / 3600,
+        secs % 3600 / 60,
+        secs % 60,
+    );
+    if days > 0 {
+        format!("{days}d {hours:02}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{hours:02}:{minutes:02}:{seconds:02}")
+    }
+}
+
 /// Creates the constant `DAY` and sets up the input and runner for each part.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
@@ -63,6 +91,20 @@ macro_rules! solution {
             use $crate::template::runner::*;
             let input = $crate::template::read_file("inputs", DAY);
             $( run_part($func, &input, DAY, $part); )*
+            print_resources();
         }
     };
 }
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::format_clock;
+
+    #[test]
+    fn formats_clock() {
+        assert_eq!(format_clock(59), "00:00:59");
+        assert_eq!(format_clock(3 * 3600 + 61), "03:01:01");
+        assert_eq!(format_clock(86_400 + 1), "1d 00:00:01");
+        assert_eq!(format_clock(90_061), "1d 01:01:01");
+    }
+}
@@ -0,0 +1,217 @@
+//! Recognition of the block letters some puzzles print as their answer.
+//!
+//! Two fonts are known: the small 4x6 font (e.g. 2022 day 10) and the large 6x10 font (e.g. 2018 day 10).
+//! Letters are separated by at least one column of unlit cells.
+
+/// The 4x6 font, glyphs trimmed of surrounding unlit columns.
+const FONT_6: [(char, &str); 18] = [
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', "###\n.#.\n.#.\n.#.\n.#.\n###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+/// The 6x10 font, glyphs trimmed of surrounding unlit columns.
+const FONT_10: [(char, &str); 15] = [
+    (
+        'A',
+        "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'B',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####.",
+    ),
+    (
+        'C',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####.",
+    ),
+    (
+        'E',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'F',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'G',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#",
+    ),
+    (
+        'H',
+        "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'J',
+        "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###..",
+    ),
+    (
+        'K',
+        "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#",
+    ),
+    (
+        'L',
+        "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'N',
+        "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#",
+    ),
+    (
+        'P',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'R',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#",
+    ),
+    (
+        'X',
+        "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#",
+    ),
+    (
+        'Z',
+        "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######",
+    ),
+];
+
+fn is_lit(c: char) -> bool {
+    matches!(c, '#' | '█')
+}
+
+/// Recognize the letters in a rendered answer, e.g. the multiline string of a solution.
+/// Returns `None` if the text is not made of known glyphs.
+pub fn recognize(text: &str) -> Option<String> {
+    let cells: Vec<Vec<bool>> = text
+        .lines()
+        .map(|line| line.chars().map(is_lit).collect())
+        .collect();
+    recognize_grid(&cells)
+}
+
+/// Recognize the letters in a grid of lit cells, indexed by row and column.
+pub fn recognize_grid(cells: &[Vec<bool>]) -> Option<String> {
+    // drop unlit rows above and below the letters.
+    let first = cells.iter().position(|row| row.contains(&true))?;
+    let last = cells.iter().rposition(|row| row.contains(&true))?;
+    let rows = &cells[first..=last];
+
+    let font: &[(char, &str)] = match rows.len() {
+        6 => &FONT_6,
+        10 => &FONT_10,
+        _ => return None,
+    };
+
+    let width = rows.iter().map(Vec::len).max()?;
+    let is_column_lit = |col: usize| {
+        rows.iter()
+            .any(|row| row.get(col).copied().unwrap_or(false))
+    };
+
+    let mut letters = String::new();
+    let mut col = 0;
+    while col < width {
+        if !is_column_lit(col) {
+            col += 1;
+            continue;
+        }
+
+        let start = col;
+        while col < width && is_column_lit(col) {
+            col += 1;
+        }
+
+        let glyph = rows
+            .iter()
+            .map(|row| {
+                (start..col)
+                    .map(|c| {
+                        if row.get(c).copied().unwrap_or(false) {
+                            '#'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let (letter, _) = font.iter().find(|(_, pattern)| *pattern == glyph)?;
+        letters.push(*letter);
+    }
+
+    Some(letters)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{FONT_6, FONT_10, recognize};
+
+    /// Lay out glyphs of a font next to each other, separated by `gap` unlit columns.
+    fn render(font: &[(char, &str)], text: &str, gap: usize) -> String {
+        let glyphs: Vec<Vec<&str>> = text
+            .chars()
+            .map(|c| {
+                font.iter()
+                    .find(|(l, _)| *l == c)
+                    .unwrap()
+                    .1
+                    .lines()
+                    .collect()
+            })
+            .collect();
+        (0..glyphs[0].len())
+            .map(|row| {
+                glyphs
+                    .iter()
+                    .map(|g| g[row])
+                    .collect::<Vec<_>>()
+                    .join(&".".repeat(gap))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn recognizes_small_font() {
+        let text = render(&FONT_6, "HELLO", 1);
+        assert_eq!(recognize(&text), Some("HELLO".into()));
+    }
+
+    #[test]
+    fn recognizes_large_font() {
+        let text = render(&FONT_10, "NXJZ", 2);
+        assert_eq!(recognize(&text), Some("NXJZ".into()));
+    }
+
+    #[test]
+    fn recognizes_padded_output() {
+        // 2022 day 10 style: 40 columns, letters in 5 column cells, spaces for unlit pixels.
+        let text = format!("\n{}\n\n", render(&FONT_6, "PZBGZEJB", 1))
+            .replace('.', " ")
+            .replace('#', "█");
+        assert_eq!(recognize(&text), Some("PZBGZEJB".into()));
+    }
+
+    #[test]
+    fn rejects_unknown_glyphs() {
+        assert_eq!(recognize("#.#\n.#.\n#.#"), None);
+        assert_eq!(recognize("####\n####\n####\n####\n####\n####"), None);
+        assert_eq!(recognize("no letters here"), None);
+    }
+}
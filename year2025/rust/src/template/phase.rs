@@ -0,0 +1,95 @@
+//! Scoped timers that break the duration of a solution part down into phases.
+//!
+//! ```ignore
+//! let _t = phase("parse");
+//! ```
+//!
+//! Phases can be nested, the runner prints them as an indented breakdown below each part.
+use std::cell::RefCell;
+use std::time::{Duration, Instant};
+
+/// A finished phase, `depth` is the number of phases it is nested in.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PhaseRecord {
+    pub name: String,
+    pub depth: usize,
+    pub duration: Duration,
+}
+
+#[derive(Default)]
+struct Collector {
+    depth: usize,
+    records: Vec<PhaseRecord>,
+}
+
+thread_local! {
+    static COLLECTOR: RefCell<Collector> = RefCell::new(Collector::default());
+}
+
+/// Measures a phase until it is dropped.
+#[must_use = "the phase ends when this guard is dropped"]
+pub struct PhaseGuard {
+    index: usize,
+    start: Instant,
+}
+
+/// Start timing a phase named `name`, ending when the returned guard goes out of scope.
+pub fn phase(name: &str) -> PhaseGuard {
+    let index = COLLECTOR.with_borrow_mut(|collector| {
+        collector.records.push(PhaseRecord {
+            name: name.to_string(),
+            depth: collector.depth,
+            duration: Duration::ZERO,
+        });
+        collector.depth += 1;
+        collector.records.len() - 1
+    });
+
+    PhaseGuard {
+        index,
+        start: Instant::now(),
+    }
+}
+
+impl Drop for PhaseGuard {
+    fn drop(&mut self) {
+        let duration = self.start.elapsed();
+        COLLECTOR.with_borrow_mut(|collector| {
+            collector.depth = collector.depth.saturating_sub(1);
+            if let Some(record) = collector.records.get_mut(self.index) {
+                record.duration = duration;
+            }
+        });
+    }
+}
+
+/// Remove and return the phases recorded on this thread, in the order they were started.
+pub fn take() -> Vec<PhaseRecord> {
+    COLLECTOR.with_borrow_mut(|collector| std::mem::take(&mut collector.records))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{phase, take};
+
+    #[test]
+    fn records_nested_phases() {
+        take();
+        {
+            let _t = phase("parse");
+            {
+                let _t = phase("tokenize");
+            }
+        }
+        {
+            let _t = phase("search");
+        }
+
+        let records = take();
+        let names: Vec<(&str, usize)> =
+            records.iter().map(|r| (r.name.as_str(), r.depth)).collect();
+        assert_eq!(names, [("parse", 0), ("tokenize", 1), ("search", 0)]);
+        assert!(records[0].duration >= records[1].duration);
+        assert!(take().is_empty());
+    }
+}
@@ -0,0 +1,229 @@
+/// Local store of puzzle descriptions, as written by aoc-cli to `data/puzzles/{day}.md`.
+use std::fs;
+
+use crate::template::answers::Answers;
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
+
+const ANSI_DIM: &str = "\x1b[2m";
+
+/// Read the stored puzzle description of a day, if it was downloaded.
+pub fn read(day: Day) -> Option<String> {
+    fs::read_to_string(aoc_cli::get_puzzle_path(day))
+        .ok()
+        .filter(|s| !s.trim().is_empty())
+}
+
+/// Number of puzzle parts (0, 1 or 2) contained in a puzzle description.
+/// Part two only becomes visible after part one has been solved.
+pub fn parts_present(markdown: &str) -> u8 {
+    if markdown.contains("--- Part Two ---") {
+        2
+    } else if markdown.contains("--- Day ") {
+        1
+    } else {
+        0
+    }
+}
+
+/// Answers shown in a puzzle description, in part order. The page lists the answer of every solved part,
+/// so this also covers parts that were solved on the website.
+pub fn solved_answers(markdown: &str) -> Vec<String> {
+    const PREFIX: &str = "Your puzzle answer was `";
+    markdown
+        .match_indices(PREFIX)
+        .filter_map(|(index, _)| {
+            let rest = &markdown[index + PREFIX.len()..];
+            rest.find('`').map(|end| rest[..end].to_string())
+        })
+        .collect()
+}
+
+/// Number of stars (0, 1 or 2) collected for a day, from the answers submitted here
+/// and from the solved parts listed in its puzzle description, if stored.
+pub fn stars(day: Day, answers: &Answers, markdown: Option<&str>) -> u8 {
+    let solved_on_page = markdown.map_or(0, |markdown| solved_answers(markdown).len());
+    (1..=2)
+        .map(|part| {
+            u8::from(answers.get(day, part).is_some() || solved_on_page >= usize::from(part))
+        })
+        .sum()
+}
+
+/// Whether a stored puzzle description predates part two, even though part one has been solved,
+/// here or on the website.
+pub fn is_outdated(day: Day, answers: &Answers, markdown: &str) -> bool {
+    parts_present(markdown) < 2 && stars(day, answers, Some(markdown)) > 0
+}
+
+/// Number of puzzle parts stored for a day.
+pub fn stored_parts(day: Day) -> u8 {
+    read(day).map_or(0, |markdown| parts_present(&markdown))
+}
+
+/// Download the puzzle description again, e.g. to pick up part two.
+pub fn refresh(day: Day) -> Result<(), aoc_cli::AocCommandError> {
+    aoc_cli::download_puzzle(day)?;
+    Ok(())
+}
+
+/// Remove the backslash escapes aoc-cli adds to markdown punctuation.
+fn unescape(line: &str) -> String {
+    let mut result = String::with_capacity(line.len());
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\\' && chars.peek().is_some_and(char::is_ascii_punctuation) {
+            result.extend(chars.next());
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+/// Replace inline `*emphasis*`, `**strong**` and `` `code` `` markers with terminal formatting.
+fn render_inline(line: &str) -> String {
+    let line = unescape(line);
+    let mut result = String::with_capacity(line.len());
+    let mut rest = line.as_str();
+
+    while let Some(index) = rest.find(['*', '`']) {
+        result.push_str(&rest[..index]);
+        rest = &rest[index..];
+
+        let (marker, style) = if rest.starts_with("**") {
+            ("**", ANSI_BOLD)
+        } else if rest.starts_with('*') {
+            ("*", ANSI_ITALIC)
+        } else {
+            ("`", ANSI_DIM)
+        };
+
+        match rest[marker.len()..].find(marker) {
+            Some(end) if end > 0 => {
+                let text = &rest[marker.len()..marker.len() + end];
+                result.push_str(&format!("{style}{text}{ANSI_RESET}"));
+                rest = &rest[2 * marker.len() + end..];
+            }
+            _ => {
+                result.push_str(marker);
+                rest = &rest[marker.len()..];
+            }
+        }
+    }
+
+    result.push_str(rest);
+    result
+}
+
+/// Render puzzle markdown with basic terminal formatting: headings, emphasis and code blocks.
+pub fn render(markdown: &str) -> String {
+    let mut lines = vec![];
+    let mut in_code_block = false;
+
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+
+        if in_code_block {
+            lines.push(format!("    {ANSI_DIM}{line}{ANSI_RESET}"));
+        } else if let Some(heading) = line.strip_prefix('#') {
+            let heading = unescape(heading.trim_start_matches('#').trim());
+            lines.push(format!("{ANSI_BOLD}{heading}{ANSI_RESET}"));
+        } else if line.starts_with("---") && line.chars().all(|c| c == '-') {
+            // setext heading underline.
+            continue;
+        } else if line.trim_start().starts_with("\\---") || line.trim_start().starts_with("---") {
+            lines.push(format!("{ANSI_BOLD}{}{ANSI_RESET}", unescape(line.trim())));
+        } else {
+            lines.push(render_inline(line));
+        }
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{is_outdated, parts_present, render, render_inline, solved_answers, stars};
+    use crate::day;
+    use crate::template::answers::Answers;
+    use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+    const PART_ONE: &str = "\\--- Day 1: Secret Entrance ---\n----------\n\nThe *dial* starts at `50`.\n\n```\nL68\nL30\n```\n";
+
+    #[test]
+    fn counts_parts() {
+        assert_eq!(parts_present(""), 0);
+        assert_eq!(parts_present(PART_ONE), 1);
+        assert_eq!(
+            parts_present(&format!("{PART_ONE}\n\\--- Part Two ---\n----------\n")),
+            2
+        );
+    }
+
+    #[test]
+    fn finds_solved_answers() {
+        assert!(solved_answers(PART_ONE).is_empty());
+
+        let solved = format!(
+            "{PART_ONE}\nYour puzzle answer was `1092`.\n\n\\--- Part Two ---\n----------\n\nYour puzzle answer was `6616`.\n"
+        );
+        assert_eq!(solved_answers(&solved), vec!["1092", "6616"]);
+    }
+
+    #[test]
+    fn counts_stars_from_answers_and_page() {
+        let mut answers = Answers::default();
+        assert_eq!(stars(day!(1), &answers, None), 0);
+
+        let solved = format!("{PART_ONE}\nYour puzzle answer was `1092`.\n");
+        assert_eq!(stars(day!(1), &answers, Some(&solved)), 1);
+
+        answers.record(day!(1), 2, "6616");
+        assert_eq!(stars(day!(1), &answers, Some(&solved)), 2);
+        assert_eq!(stars(day!(1), &answers, Some(PART_ONE)), 1);
+    }
+
+    #[test]
+    fn detects_outdated_descriptions() {
+        let mut answers = Answers::default();
+        assert!(!is_outdated(day!(1), &answers, PART_ONE));
+
+        // solved on the website, but downloaded before part two was shown.
+        let solved = format!("{PART_ONE}\nYour puzzle answer was `1092`.\n");
+        assert!(is_outdated(day!(1), &answers, &solved));
+
+        answers.record(day!(1), 1, "1092");
+        assert!(is_outdated(day!(1), &answers, PART_ONE));
+        assert!(!is_outdated(
+            day!(1),
+            &answers,
+            &format!("{PART_ONE}\n\\--- Part Two ---\n")
+        ));
+    }
+
+    #[test]
+    fn renders_inline_formatting() {
+        assert_eq!(
+            render_inline("a *b* and **c**"),
+            format!("a {ANSI_ITALIC}b{ANSI_RESET} and {ANSI_BOLD}c{ANSI_RESET}")
+        );
+        assert_eq!(render_inline("2 \\* 3 = 6"), "2 * 3 = 6");
+        assert_eq!(render_inline("unmatched * star"), "unmatched * star");
+    }
+
+    #[test]
+    fn renders_markdown() {
+        let output = render(PART_ONE);
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(
+            lines[0],
+            format!("{ANSI_BOLD}--- Day 1: Secret Entrance ---{ANSI_RESET}")
+        );
+        assert!(lines[2].contains(&format!("{ANSI_ITALIC}dial{ANSI_RESET}")));
+        assert!(lines[4].starts_with("    ") && lines[4].contains("L68"));
+        assert!(!output.contains("```"));
+    }
+}
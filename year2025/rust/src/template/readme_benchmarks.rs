@@ -3,6 +3,7 @@
 use std::{fs, io};
 
 use crate::template::Day;
+use crate::template::report::{Format, Report};
 use crate::template::timings::Timings;
 
 static MARKER: &str = "<!--- benchmarking table --->";
@@ -52,38 +53,25 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
+fn construct_table(prefix: &str, timings: &Timings, total_millis: f64) -> String {
     let header = format!("{prefix} Benchmarks");
+    let table = Report::new(timings).for_readme().render(Format::Markdown);
 
-    let mut lines: Vec<String> = vec![
+    [
         MARKER.into(),
         header,
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
-
-    for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
-    }
-
-    lines.push(String::new());
-    lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
-
-    lines.join("\n")
+        table,
+        String::new(),
+        format!("**Total: {total_millis:.2}ms**"),
+        MARKER.into(),
+    ]
+    .join("\n")
 }
 
 fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
     let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+    let table = construct_table("##", &timings, total_millis);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
@@ -110,18 +98,24 @@ mod tests {
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
+                    phases: vec![],
+                    resources: None,
                 },
                 Timing {
                     day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
+                    phases: vec![],
+                    resources: None,
                 },
                 Timing {
                     day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: Some("50ms".into()),
                     total_nanos: 9e+10,
+                    phases: vec![],
+                    resources: None,
                 },
             ],
         }
@@ -0,0 +1,512 @@
+/// Shared request layer for everything that talks to the Advent of Code website.
+///
+/// Requests are throttled through a timestamp persisted in `data/.last_request`, so that separate
+/// invocations (and aoc-cli calls) keep a minimum interval between requests. GET responses asking
+/// us to wait are retried after the requested delay, POSTs are never resent. HTTP is done by the
+/// `curl` command-line, the session cookie is passed on its stdin so it never shows up in `ps`.
+use std::{
+    env,
+    fmt::Display,
+    fs,
+    io::Write,
+    path::PathBuf,
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::template::Day;
+
+static LAST_REQUEST_FILE_PATH: &str = "data/.last_request";
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const DEFAULT_USER_AGENT: &str = "github.com/bnorm/advent-of-code (advent_of_code template)";
+const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Number of times a GET request is retried when the response asks us to wait.
+const MAX_RETRIES: u32 = 3;
+/// Upper bound for a requested wait, longer waits are reported as an error instead.
+const MAX_WAIT: Duration = Duration::from_secs(5 * 60);
+
+#[derive(Debug)]
+pub enum RequestError {
+    CurlNotCallable,
+    CurlFailed(String),
+    NoSession,
+    NoYear,
+    BadStatus(u16),
+    RateLimited(Duration),
+    IO(std::io::Error),
+}
+
+impl Display for RequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RequestError::CurlNotCallable => {
+                write!(f, "command \"curl\" not found or not callable.")
+            }
+            RequestError::CurlFailed(stderr) => write!(f, "curl failed: {stderr}"),
+            RequestError::NoSession => write!(
+                f,
+                "no session cookie found. Set ADVENT_OF_CODE_SESSION or create ~/.adventofcode.session."
+            ),
+            RequestError::NoYear => write!(f, "AOC_YEAR is not set."),
+            RequestError::BadStatus(status) => write!(f, "request failed with status {status}."),
+            RequestError::RateLimited(wait) => {
+                write!(f, "rate limited, try again in {}s.", wait.as_secs())
+            }
+            RequestError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<std::io::Error> for RequestError {
+    fn from(e: std::io::Error) -> Self {
+        RequestError::IO(e)
+    }
+}
+
+/// Session cookie, looked up the same way aoc-cli does.
+fn session() -> Option<String> {
+    if let Ok(session) = env::var("ADVENT_OF_CODE_SESSION") {
+        return Some(session);
+    }
+
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+    let session = fs::read_to_string(PathBuf::from(home).join(".adventofcode.session")).ok()?;
+    Some(session.trim().to_string())
+}
+
+fn unix_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| u64::try_from(d.as_millis()).unwrap_or(u64::MAX))
+}
+
+/// Enforces a minimum interval between requests, across processes.
+pub struct Throttle {
+    pub path: PathBuf,
+    pub min_interval: Duration,
+}
+
+impl Throttle {
+    /// Configured via `AOC_MIN_INTERVAL` (in seconds).
+    pub fn from_env() -> Self {
+        Throttle {
+            path: PathBuf::from(LAST_REQUEST_FILE_PATH),
+            min_interval: env::var("AOC_MIN_INTERVAL")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .map_or(DEFAULT_MIN_INTERVAL, Duration::from_secs),
+        }
+    }
+
+    /// Block until the minimum interval since the last request has passed, then record a new request.
+    pub fn wait(&self) {
+        let last = fs::read_to_string(&self.path)
+            .ok()
+            .and_then(|s| s.trim().parse::<u64>().ok())
+            .unwrap_or(0);
+        let elapsed = Duration::from_millis(unix_millis().saturating_sub(last));
+
+        if let Some(remaining) = self.min_interval.checked_sub(elapsed) {
+            thread::sleep(remaining);
+        }
+
+        if let Some(parent) = self.path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        let _ = fs::write(&self.path, unix_millis().to_string());
+    }
+}
+
+/// Parse a response asking us to wait, e.g. "Please wait 30 seconds" or "you have 1m 5s left to wait".
+pub fn parse_wait(body: &str) -> Option<Duration> {
+    let lower = body.to_lowercase();
+
+    if let Some(index) = lower.find("please wait ") {
+        let rest = &lower[index + "please wait ".len()..];
+        let (amount, unit) = rest.split_once(' ')?;
+        let amount: u64 = match amount {
+            "one" | "a" => 1,
+            n => n.parse().ok()?,
+        };
+        return match unit {
+            u if u.starts_with("second") => Some(Duration::from_secs(amount)),
+            u if u.starts_with("minute") => Some(Duration::from_secs(amount * 60)),
+            _ => None,
+        };
+    }
+
+    let end = lower.find(" left to wait")?;
+    let start = lower[..end].rfind("you have ")? + "you have ".len();
+    lower[start..end]
+        .split_whitespace()
+        .map(|part| {
+            if let Some(m) = part.strip_suffix('m') {
+                m.parse::<u64>().ok().map(|m| m * 60)
+            } else {
+                part.strip_suffix('s')?.parse::<u64>().ok()
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// The verdict of the website on a submitted answer.
+#[derive(Debug, PartialEq, Eq)]
+pub enum SubmissionOutcome {
+    Correct,
+    Incorrect,
+    TooRecent,
+    AlreadyCompleted,
+    Unknown,
+}
+
+impl SubmissionOutcome {
+    pub fn parse(output: &str) -> Self {
+        if output.contains("That's the right answer") {
+            SubmissionOutcome::Correct
+        } else if output.contains("That's not the right answer") {
+            SubmissionOutcome::Incorrect
+        } else if output.contains("You gave an answer too recently") {
+            SubmissionOutcome::TooRecent
+        } else if output.contains("Did you already complete it") {
+            SubmissionOutcome::AlreadyCompleted
+        } else {
+            SubmissionOutcome::Unknown
+        }
+    }
+}
+
+/// A curl config line setting the session cookie, with the value quoted as curl expects.
+fn curl_config_cookie(session: &str) -> String {
+    let escaped = session.replace('\\', "\\\\").replace('"', "\\\"");
+    format!("cookie = \"session={escaped}\"\n")
+}
+
+/// Extracts the readable message from a response page, i.e. the text of its `<article>`.
+fn article_text(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(html, |(article, _)| article);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+pub struct Client {
+    pub base_url: String,
+    pub user_agent: String,
+    pub session: Option<String>,
+    pub throttle: Throttle,
+}
+
+impl Client {
+    /// Configured via `AOC_BASE_URL`, `AOC_USER_AGENT` and `AOC_MIN_INTERVAL`.
+    pub fn from_env() -> Self {
+        Client {
+            base_url: env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into()),
+            user_agent: env::var("AOC_USER_AGENT").unwrap_or_else(|_| DEFAULT_USER_AGENT.into()),
+            session: session(),
+            throttle: Throttle::from_env(),
+        }
+    }
+
+    /// GET `path`, retrying after the requested delay when the response asks us to wait.
+    pub fn get(&self, path: &str) -> Result<String, RequestError> {
+        let mut retries = 0;
+        loop {
+            let body = self.send(path, &[])?;
+
+            let Some(wait) = parse_wait(&body) else {
+                return Ok(body);
+            };
+            if retries >= MAX_RETRIES || wait > MAX_WAIT {
+                return Err(RequestError::RateLimited(wait));
+            }
+            eprintln!("Asked to wait {}s before retrying...", wait.as_secs());
+            thread::sleep(wait);
+            retries += 1;
+        }
+    }
+
+    /// POST `form` to `path`. Never resent, the caller decides what to do with a response asking to wait.
+    pub fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String, RequestError> {
+        let mut args = vec![];
+        for (key, value) in form {
+            args.push("--data-urlencode".to_string());
+            args.push(format!("{key}={value}"));
+        }
+        self.send(path, &args)
+    }
+
+    /// Send a single request. The session cookie goes through a curl config read from stdin (`-K -`).
+    fn send(&self, path: &str, extra_args: &[String]) -> Result<String, RequestError> {
+        let session = self.session.as_ref().ok_or(RequestError::NoSession)?;
+
+        self.throttle.wait();
+
+        let mut child = Command::new("curl")
+            .args(["--config", "-"])
+            .args(["--silent", "--show-error", "--user-agent", &self.user_agent])
+            .args(["--write-out", "\n%{http_code}"])
+            .args(extra_args)
+            .arg(format!("{}{path}", self.base_url))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|_| RequestError::CurlNotCallable)?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(curl_config_cookie(session).as_bytes())?;
+        }
+        let output = child.wait_with_output()?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+            return Err(RequestError::CurlFailed(if stderr.is_empty() {
+                output.status.to_string()
+            } else {
+                stderr
+            }));
+        }
+
+        let response = String::from_utf8_lossy(&output.stdout);
+        let (body, status) = response.rsplit_once('\n').unwrap_or(("", &response));
+        let status: u16 = status.trim().parse().unwrap_or(0);
+
+        if (200..300).contains(&status) {
+            Ok(body.to_string())
+        } else {
+            Err(RequestError::BadStatus(status))
+        }
+    }
+
+    /// Download the input of a day to `path`. Inputs never change, so an existing input is not re-fetched.
+    pub fn download_input(&self, day: Day, year: u16, path: &str) -> Result<bool, RequestError> {
+        if fs::metadata(path).is_ok_and(|m| m.len() > 0) {
+            return Ok(false);
+        }
+
+        let input = self.get(&format!("/{year}/day/{}/input", day.into_inner()))?;
+        fs::write(path, input)?;
+        Ok(true)
+    }
+
+    /// Submit an answer, returning the outcome and the message of the response.
+    ///
+    /// The answer is posted once: an answer given too recently comes back as `TooRecent` with the time left to wait.
+    pub fn submit(
+        &self,
+        day: Day,
+        year: u16,
+        part: u8,
+        answer: &str,
+    ) -> Result<(SubmissionOutcome, String), RequestError> {
+        let response = self.post(
+            &format!("/{year}/day/{}/answer", day.into_inner()),
+            &[("level", &part.to_string()), ("answer", answer)],
+        )?;
+        let message = article_text(&response);
+        Ok((SubmissionOutcome::parse(&message), message))
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        Client, RequestError, SubmissionOutcome, Throttle, article_text, curl_config_cookie,
+        parse_wait,
+    };
+    use crate::day;
+    use std::{
+        env, fs,
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        path::PathBuf,
+        sync::{Arc, Mutex},
+        thread,
+        time::{Duration, Instant},
+    };
+
+    fn temp_path(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("aoc-remote-{}-{name}", std::process::id()))
+    }
+
+    /// A fake website answering with canned `(status, body)` responses and recording request heads.
+    fn fake_server(responses: Vec<(u16, &'static str)>) -> (Client, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let requests = Arc::new(Mutex::new(vec![]));
+        let recorded = requests.clone();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut head = String::new();
+                let mut line = String::new();
+                while reader.read_line(&mut line).unwrap() > 2 {
+                    head.push_str(&line);
+                    line.clear();
+                }
+                let length = head
+                    .lines()
+                    .find_map(|l| l.strip_prefix("Content-Length: "))
+                    .map_or(0, |l| l.trim().parse().unwrap());
+                reader.read_exact(&mut vec![0; length]).unwrap();
+                recorded.lock().unwrap().push(head);
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+
+        let client = Client {
+            base_url: format!("http://127.0.0.1:{port}"),
+            user_agent: "test-agent/1.0".into(),
+            session: Some("secret".into()),
+            throttle: Throttle {
+                path: temp_path(&format!("throttle-{port}")),
+                min_interval: Duration::ZERO,
+            },
+        };
+
+        (client, requests)
+    }
+
+    #[test]
+    fn parses_wait_messages() {
+        assert_eq!(
+            parse_wait("Please wait 30 seconds."),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            parse_wait("please wait one minute before trying again"),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(
+            parse_wait("You gave an answer too recently; you have 1m 5s left to wait."),
+            Some(Duration::from_secs(65))
+        );
+        assert_eq!(parse_wait("That's the right answer!"), None);
+    }
+
+    #[test]
+    fn extracts_article_text() {
+        let html =
+            "<html><main><article><p>That's the <em>right</em> answer!</p></article></main></html>";
+        assert_eq!(article_text(html), "That's the right answer!");
+    }
+
+    #[test]
+    fn sends_user_agent_and_session() {
+        let (client, requests) = fake_server(vec![(200, "hello")]);
+        assert_eq!(client.get("/2025/day/1/input").unwrap(), "hello");
+
+        let head = requests.lock().unwrap()[0].clone();
+        assert!(head.starts_with("GET /2025/day/1/input "));
+        assert!(head.contains("User-Agent: test-agent/1.0"));
+        assert!(head.contains("Cookie: session=secret"));
+    }
+
+    #[test]
+    fn retries_when_asked_to_wait() {
+        let (client, requests) = fake_server(vec![(200, "Please wait 1 second."), (200, "done")]);
+        let start = Instant::now();
+        assert_eq!(client.get("/").unwrap(), "done");
+        assert!(start.elapsed() >= Duration::from_secs(1));
+        assert_eq!(requests.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn throttles_requests() {
+        let (mut client, _) = fake_server(vec![(200, "a"), (200, "b")]);
+        client.throttle.min_interval = Duration::from_millis(300);
+        let start = Instant::now();
+        client.get("/").unwrap();
+        client.get("/").unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(300));
+    }
+
+    #[test]
+    fn caches_inputs() {
+        let (client, requests) = fake_server(vec![(200, "1\n2\n3\n")]);
+        let path = temp_path("input.txt");
+        let path = path.to_str().unwrap();
+        let _ = fs::remove_file(path);
+
+        assert!(client.download_input(day!(1), 2025, path).unwrap());
+        assert!(!client.download_input(day!(1), 2025, path).unwrap());
+        assert_eq!(fs::read_to_string(path).unwrap(), "1\n2\n3\n");
+        assert_eq!(requests.lock().unwrap().len(), 1);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn reports_bad_status() {
+        let (client, _) = fake_server(vec![(404, "not found")]);
+        assert!(matches!(client.get("/"), Err(RequestError::BadStatus(404))));
+    }
+
+    #[test]
+    fn reports_curl_failures() {
+        let port = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let (mut client, _) = fake_server(vec![]);
+        client.base_url = format!("http://127.0.0.1:{port}");
+        assert!(matches!(client.get("/"), Err(RequestError::CurlFailed(_))));
+    }
+
+    #[test]
+    fn quotes_session_in_curl_config() {
+        assert_eq!(curl_config_cookie("abc"), "cookie = \"session=abc\"\n");
+        assert_eq!(
+            curl_config_cookie("a\"b\\c"),
+            "cookie = \"session=a\\\"b\\\\c\"\n"
+        );
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (client, requests) = fake_server(vec![(
+            200,
+            "<article><p>That's the right answer! You are one gold star closer.</p></article>",
+        )]);
+        let (outcome, message) = client.submit(day!(3), 2025, 2, "42").unwrap();
+        assert_eq!(outcome, SubmissionOutcome::Correct);
+        assert!(message.starts_with("That's the right answer!"));
+        assert!(requests.lock().unwrap()[0].starts_with("POST /2025/day/3/answer "));
+    }
+
+    #[test]
+    fn never_resubmits_answers() {
+        let (client, requests) = fake_server(vec![(
+            200,
+            "<article><p>You gave an answer too recently; you have 1m 5s left to wait.</p></article>",
+        )]);
+        let (outcome, message) = client.submit(day!(3), 2025, 1, "42").unwrap();
+        assert_eq!(outcome, SubmissionOutcome::TooRecent);
+        assert_eq!(parse_wait(&message), Some(Duration::from_secs(65)));
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,449 @@
+/// Renders stored timings (and benchmark history, if present) as CSV, a Markdown table or a standalone HTML page.
+use std::{fmt::Write, str::FromStr};
+
+use crate::template::history::History;
+use crate::template::readme_benchmarks::get_path_for_bin;
+use crate::template::timings::{Timing, Timings};
+
+const CHART_WIDTH: f64 = 600.0;
+const BAR_HEIGHT: f64 = 18.0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Csv,
+    Markdown,
+    Html,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "csv" => Ok(Format::Csv),
+            "md" | "markdown" => Ok(Format::Markdown),
+            "html" => Ok(Format::Html),
+            _ => Err(format!(
+                "unknown report format `{s}`, expected csv, md or html."
+            )),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SortBy {
+    #[default]
+    Day,
+    /// Slowest day first.
+    Runtime,
+}
+
+impl FromStr for SortBy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "day" => Ok(SortBy::Day),
+            "runtime" | "time" => Ok(SortBy::Runtime),
+            _ => Err(format!(
+                "unknown sort order `{s}`, expected day or runtime."
+            )),
+        }
+    }
+}
+
+pub struct Report<'a> {
+    timings: &'a Timings,
+    history: Option<&'a History>,
+    sort_by: SortBy,
+    percentage: bool,
+    readme: bool,
+}
+
+impl<'a> Report<'a> {
+    pub fn new(timings: &'a Timings) -> Self {
+        Report {
+            timings,
+            history: None,
+            sort_by: SortBy::Day,
+            percentage: true,
+            readme: false,
+        }
+    }
+
+    /// Add the number of stored runs and the best run of each day, if there are any.
+    #[must_use]
+    pub fn with_history(mut self, history: &'a History) -> Self {
+        if !history.data.is_empty() {
+            self.history = Some(history);
+        }
+        self
+    }
+
+    #[must_use]
+    pub fn sorted_by(mut self, sort_by: SortBy) -> Self {
+        self.sort_by = sort_by;
+        self
+    }
+
+    /// Whether to add a column with each day's share of the total runtime.
+    #[must_use]
+    pub fn with_percentage(mut self, percentage: bool) -> Self {
+        self.percentage = percentage;
+        self
+    }
+
+    /// Render the Markdown table as the README's benchmark table: only the day and part columns,
+    /// with the separator row it has always had.
+    #[must_use]
+    pub fn for_readme(mut self) -> Self {
+        self.readme = true;
+        self.percentage = false;
+        self.history = None;
+        self
+    }
+
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Csv => self.to_csv(),
+            Format::Markdown => self.to_markdown(),
+            Format::Html => self.to_html(),
+        }
+    }
+
+    fn rows(&self) -> Vec<&Timing> {
+        let mut rows: Vec<&Timing> = self.timings.data.iter().collect();
+        match self.sort_by {
+            SortBy::Day => rows.sort_by_key(|t| t.day),
+            SortBy::Runtime => rows.sort_by(|a, b| b.total_nanos.total_cmp(&a.total_nanos)),
+        }
+        rows
+    }
+
+    fn percentage_of_total(&self, timing: &Timing) -> f64 {
+        let total: f64 = self.timings.data.iter().map(|t| t.total_nanos).sum();
+        if total > 0_f64 {
+            timing.total_nanos / total * 100_f64
+        } else {
+            0_f64
+        }
+    }
+
+    /// Number of stored runs and the fastest of them in nanoseconds.
+    fn history_stats(&self, timing: &Timing) -> Option<(usize, Option<f64>)> {
+        let runs: Vec<f64> = self
+            .history?
+            .for_day(timing.day)
+            .map(|e| e.total_nanos)
+            .collect();
+        Some((runs.len(), runs.iter().copied().reduce(f64::min)))
+    }
+
+    fn headers(&self) -> Vec<&'static str> {
+        let mut headers = vec!["Day", "Part 1", "Part 2"];
+        if self.percentage {
+            headers.push("%");
+        }
+        if self.history.is_some() {
+            headers.extend(["Runs", "Best"]);
+        }
+        headers
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut headers = vec!["day", "part_1", "part_2", "total_nanos"];
+        if self.percentage {
+            headers.push("percentage");
+        }
+        if self.history.is_some() {
+            headers.extend(["runs", "best_nanos"]);
+        }
+
+        let mut lines = vec![headers.join(",")];
+        for timing in self.rows() {
+            let mut fields = vec![
+                timing.day.to_string(),
+                timing.part_1.clone().unwrap_or_default(),
+                timing.part_2.clone().unwrap_or_default(),
+                timing.total_nanos.to_string(),
+            ];
+            if self.percentage {
+                fields.push(format!("{:.2}", self.percentage_of_total(timing)));
+            }
+            if let Some((runs, best)) = self.history_stats(timing) {
+                fields.extend([
+                    runs.to_string(),
+                    best.map_or(String::new(), |b| b.to_string()),
+                ]);
+            }
+            lines.push(fields.join(","));
+        }
+
+        lines.join("\n") + "\n"
+    }
+
+    /// A Markdown table without surrounding text, one row per day.
+    pub fn to_markdown(&self) -> String {
+        let headers = self.headers();
+        let mut separator = format!("|{}", " :---: |".repeat(headers.len()));
+        if self.readme {
+            separator.insert(separator.len() - 1, ' ');
+        }
+        let mut lines = vec![format!("| {} |", headers.join(" | ")), separator];
+
+        for timing in self.rows() {
+            let mut cells = vec![
+                format!(
+                    "[Day {}]({})",
+                    timing.day.into_inner(),
+                    get_path_for_bin(timing.day)
+                ),
+                format!("`{}`", timing.part_1.as_deref().unwrap_or("-")),
+                format!("`{}`", timing.part_2.as_deref().unwrap_or("-")),
+            ];
+            if self.percentage {
+                cells.push(format!("{:.1}%", self.percentage_of_total(timing)));
+            }
+            if let Some((runs, best)) = self.history_stats(timing) {
+                cells.extend([runs.to_string(), best.map_or("-".into(), format_millis)]);
+            }
+            lines.push(format!("| {} |", cells.join(" | ")));
+        }
+
+        lines.join("\n")
+    }
+
+    /// A standalone HTML page with a bar chart of the runtime per day and a table of the timings.
+    pub fn to_html(&self) -> String {
+        let rows = self.rows();
+
+        let mut html = String::new();
+        html.push_str(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>Advent of Code Benchmarks</title>\n\
+             <style>\n\
+             body { font-family: monospace; background: #0f0f23; color: #cccccc; margin: 2em; }\n\
+             h1 { color: #ffff66; }\n\
+             table { border-collapse: collapse; }\n\
+             td, th { padding: 0.2em 1em; text-align: left; }\n\
+             rect { fill: #009900; }\n\
+             text { fill: #cccccc; font-family: monospace; font-size: 12px; }\n\
+             </style>\n</head>\n<body>\n",
+        );
+
+        let _ = writeln!(html, "<h1>Benchmarks</h1>");
+        let _ = writeln!(html, "<p>Total: {:.2}ms</p>", self.timings.total_millis());
+
+        let bars: Vec<(String, f64)> = rows
+            .iter()
+            .map(|t| (format!("Day {}", t.day), t.total_nanos))
+            .collect();
+        html.push_str(&bar_chart(&bars));
+
+        let _ = writeln!(html, "<table>");
+        let _ = writeln!(
+            html,
+            "<tr>{}</tr>",
+            self.headers()
+                .iter()
+                .map(|h| format!("<th>{h}</th>"))
+                .collect::<String>()
+        );
+        for timing in rows {
+            let mut cells = vec![
+                timing.day.to_string(),
+                escape(timing.part_1.as_deref().unwrap_or("-")),
+                escape(timing.part_2.as_deref().unwrap_or("-")),
+            ];
+            if self.percentage {
+                cells.push(format!("{:.1}%", self.percentage_of_total(timing)));
+            }
+            if let Some((runs, best)) = self.history_stats(timing) {
+                cells.extend([runs.to_string(), best.map_or("-".into(), format_millis)]);
+            }
+            let _ = writeln!(
+                html,
+                "<tr>{}</tr>",
+                cells
+                    .iter()
+                    .map(|c| format!("<td>{c}</td>"))
+                    .collect::<String>()
+            );
+        }
+        let _ = writeln!(html, "</table>");
+
+        html.push_str("</body>\n</html>\n");
+        html
+    }
+}
+
+fn format_millis(nanos: f64) -> String {
+    format!("{:.2}ms", nanos / 1_000_000_f64)
+}
+
+/// Horizontal SVG bar chart of runtimes in nanoseconds, one labeled bar per entry.
+pub fn bar_chart(bars: &[(String, f64)]) -> String {
+    let max_nanos = bars.iter().map(|(_, nanos)| *nanos).fold(0_f64, f64::max);
+
+    let mut svg = String::new();
+    #[allow(clippy::cast_precision_loss)]
+    let chart_height = BAR_HEIGHT * bars.len() as f64;
+    let _ = writeln!(
+        svg,
+        "<svg width=\"{}\" height=\"{chart_height}\">",
+        CHART_WIDTH + 160.0
+    );
+    for (index, (label, nanos)) in bars.iter().enumerate() {
+        let width = if max_nanos > 0_f64 {
+            nanos / max_nanos * CHART_WIDTH
+        } else {
+            0_f64
+        };
+        #[allow(clippy::cast_precision_loss)]
+        let y = BAR_HEIGHT * index as f64;
+        let _ = writeln!(
+            svg,
+            "<text x=\"0\" y=\"{}\">{}</text>\
+             <rect x=\"60\" y=\"{}\" width=\"{width:.1}\" height=\"{}\"></rect>\
+             <text x=\"{:.1}\" y=\"{}\">{}</text>",
+            y + 13.0,
+            escape(label),
+            y + 2.0,
+            BAR_HEIGHT - 4.0,
+            width + 66.0,
+            y + 13.0,
+            format_millis(*nanos)
+        );
+    }
+    let _ = writeln!(svg, "</svg>");
+    svg
+}
+
+pub fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Format, Report, SortBy};
+    use crate::{
+        day,
+        template::history::{History, HistoryEntry},
+        template::timings::{Timing, Timings},
+    };
+
+    fn get_mock_timings() -> Timings {
+        Timings {
+            data: vec![
+                Timing {
+                    day: day!(1),
+                    part_1: Some("10ms".into()),
+                    part_2: Some("20ms".into()),
+                    total_nanos: 3e+7,
+                    phases: vec![],
+                    resources: None,
+                },
+                Timing {
+                    day: day!(2),
+                    part_1: Some("30ms".into()),
+                    part_2: None,
+                    total_nanos: 7e+7,
+                    phases: vec![],
+                    resources: None,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn renders_csv() {
+        let timings = get_mock_timings();
+        let csv = Report::new(&timings).render(Format::Csv);
+        assert_eq!(
+            csv,
+            "day,part_1,part_2,total_nanos,percentage\n01,10ms,20ms,30000000,30.00\n02,30ms,,70000000,70.00\n"
+        );
+    }
+
+    #[test]
+    fn renders_markdown_sorted_by_runtime() {
+        let timings = get_mock_timings();
+        let markdown = Report::new(&timings)
+            .sorted_by(SortBy::Runtime)
+            .render(Format::Markdown);
+        let lines: Vec<&str> = markdown.lines().collect();
+        assert_eq!(lines[0], "| Day | Part 1 | Part 2 | % |");
+        assert_eq!(lines[1], "| :---: | :---: | :---: | :---: |");
+        assert_eq!(
+            lines[2],
+            "| [Day 2](./src/bin/02.rs) | `30ms` | `-` | 70.0% |"
+        );
+        assert_eq!(
+            lines[3],
+            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` | 30.0% |"
+        );
+    }
+
+    #[test]
+    fn renders_history_columns() {
+        let timings = get_mock_timings();
+        let history = History {
+            data: vec![
+                HistoryEntry {
+                    day: day!(1),
+                    timestamp: 1,
+                    total_nanos: 4e+7,
+                },
+                HistoryEntry {
+                    day: day!(1),
+                    timestamp: 2,
+                    total_nanos: 2e+7,
+                },
+            ],
+        };
+        let markdown = Report::new(&timings)
+            .with_history(&history)
+            .with_percentage(false)
+            .render(Format::Markdown);
+        assert!(markdown.starts_with("| Day | Part 1 | Part 2 | Runs | Best |"));
+        assert!(markdown.contains("| `20ms` | 2 | 20.00ms |"));
+        assert!(markdown.contains("| `-` | 0 | - |"));
+    }
+
+    #[test]
+    fn renders_html() {
+        let timings = get_mock_timings();
+        let html = Report::new(&timings).render(Format::Html);
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<rect"));
+        assert!(html.contains("<td>70.0%</td>"));
+    }
+
+    #[test]
+    fn renders_readme_table() {
+        let timings = get_mock_timings();
+        let history = History { data: vec![] };
+        let markdown = Report::new(&timings)
+            .with_history(&history)
+            .for_readme()
+            .render(Format::Markdown);
+        let expected = [
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/02.rs) | `30ms` | `-` |",
+        ]
+        .join("\n");
+        assert_eq!(markdown, expected);
+    }
+
+    #[test]
+    fn parses_options() {
+        assert_eq!("md".parse::<Format>(), Ok(Format::Markdown));
+        assert_eq!("runtime".parse::<SortBy>(), Ok(SortBy::Runtime));
+        assert!("xml".parse::<Format>().is_err());
+    }
+}
@@ -0,0 +1,78 @@
+/// CPU time and memory used by the current process, to complement wall-clock timings.
+use std::fs;
+use std::time::Duration;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResourceUsage {
+    pub user: Duration,
+    pub system: Duration,
+    /// Peak resident set size in kB, only available on Linux.
+    pub peak_rss_kb: Option<u64>,
+}
+
+/// Measure the resources the current process has used so far.
+pub fn current() -> Option<ResourceUsage> {
+    let (user, system) = cpu_times()?;
+    Some(ResourceUsage {
+        user,
+        system,
+        peak_rss_kb: fs::read_to_string("/proc/self/status")
+            .ok()
+            .and_then(|status| parse_peak_rss(&status)),
+    })
+}
+
+#[cfg(unix)]
+fn cpu_times() -> Option<(Duration, Duration)> {
+    // SAFETY: `getrusage` only writes to the zero-initialized struct we pass it.
+    let usage = unsafe {
+        let mut usage: libc::rusage = std::mem::zeroed();
+        if libc::getrusage(libc::RUSAGE_SELF, &raw mut usage) != 0 {
+            return None;
+        }
+        usage
+    };
+
+    let to_duration = |time: libc::timeval| -> Option<Duration> {
+        Some(Duration::new(
+            u64::try_from(time.tv_sec).ok()?,
+            u32::try_from(time.tv_usec).ok()? * 1000,
+        ))
+    };
+
+    Some((to_duration(usage.ru_utime)?, to_duration(usage.ru_stime)?))
+}
+
+#[cfg(not(unix))]
+fn cpu_times() -> Option<(Duration, Duration)> {
+    None
+}
+
+/// Read the peak resident set size (`VmHWM`) from the contents of `/proc/self/status`.
+fn parse_peak_rss(status: &str) -> Option<u64> {
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmHWM:"))
+        .and_then(|value| value.trim().strip_suffix("kB"))
+        .and_then(|kb| kb.trim().parse().ok())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{current, parse_peak_rss};
+
+    #[test]
+    fn parses_peak_rss() {
+        let status =
+            "Name:\tday01\nVmPeak:\t   10000 kB\nVmHWM:\t    2048 kB\nVmRSS:\t    1024 kB\n";
+        assert_eq!(parse_peak_rss(status), Some(2048));
+        assert_eq!(parse_peak_rss("Name:\tday01\n"), None);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn measures_current_process() {
+        let usage = current().unwrap();
+        assert!(usage.peak_rss_kb.is_some_and(|kb| kb > 0));
+    }
+}
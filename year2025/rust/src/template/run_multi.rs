@@ -1,13 +1,22 @@
 use std::{collections::HashSet, io};
 
+use crate::template::consistency::{Tally, parse_answers};
 use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
 
 use super::{
     all_days,
-    timings::{Timing, Timings},
+    timings::{PhaseTiming, ResourceTiming, Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
+/// Run the given days in order. With `repeat > 1`, each part runs at least `repeat` times in-process
+/// and each day additionally in `repeat` fresh processes, to flag answers that differ between runs.
+pub fn run_multi(
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    is_timed: bool,
+    repeat: usize,
+    verbose: bool,
+) -> Option<Timings> {
     let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
 
     let mut need_space = false;
@@ -24,13 +33,18 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
             println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
             println!("------");
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+            let output =
+                child_commands::run_solution(day, is_timed, is_release, repeat, verbose).unwrap();
 
             if output.is_empty() {
                 println!("Not solved.");
             } else {
                 let val = child_commands::parse_exec_time(&output, day);
                 timings.push(val);
+
+                if repeat > 1 {
+                    check_fresh_processes(day, is_release, repeat);
+                }
             }
         });
 
@@ -46,6 +60,44 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
     }
 }
 
+/// Run a day in `runs` fresh processes and report the parts whose answers differ between them.
+/// Returns whether all answers were consistent.
+pub fn check_fresh_processes(day: Day, is_release: bool, runs: usize) -> bool {
+    println!("{ANSI_ITALIC}Checking answers of {runs} fresh processes...{ANSI_RESET}");
+
+    let mut tallies: [Tally; 2] = Default::default();
+    for _ in 0..runs {
+        let output = match child_commands::run_solution_quietly(day, is_release) {
+            Ok(output) => output,
+            Err(e) => {
+                eprintln!("Failed to run day {day}: {e:?}");
+                return false;
+            }
+        };
+        let answers = parse_answers(&output);
+        if answers.is_empty() {
+            eprintln!("Day {day} did not print any answers.");
+            return false;
+        }
+        for (part, answer) in answers {
+            tallies[usize::from(part - 1)].add(answer);
+        }
+    }
+
+    let mut consistent = true;
+    for (index, tally) in tallies.iter().enumerate() {
+        if !tally.is_consistent() {
+            consistent = false;
+            println!(
+                "Part {}: {ANSI_BOLD}⚠ nondeterministic between processes{ANSI_RESET}, {}",
+                index + 1,
+                tally.summary()
+            );
+        }
+    }
+    consistent
+}
+
 #[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
@@ -77,25 +129,41 @@ pub mod child_commands {
     };
 
     /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    pub fn run_solution(
+        day: Day,
+        is_timed: bool,
+        is_release: bool,
+        repeat: usize,
+        verbose: bool,
+    ) -> Result<Vec<String>, Error> {
         // skip command invocation for days that have not been scaffolded yet.
         if !Path::new(&get_path_for_bin(day)).exists() {
             return Ok(vec![]);
         }
 
         let day_padded = day.to_string();
+        let repeat_str = repeat.to_string();
         let mut args = vec!["run", "--quiet", "--bin", &day_padded];
 
         if is_release {
             args.push("--release");
         }
 
+        args.push("--");
+
         if is_timed {
             // mirror `--time` flag to child invocations.
-            args.push("--");
             args.push("--time");
         }
 
+        if repeat > 1 {
+            args.extend(["--repeat", &repeat_str]);
+        }
+
+        if verbose {
+            args.push("--verbose");
+        }
+
         // spawn child command with piped stdout/stderr.
         // forward output to stdout/stderr while grabbing stdout lines.
 
@@ -128,12 +196,34 @@ pub mod child_commands {
         Ok(output)
     }
 
+    /// Run the solution bin for a given day without forwarding its output.
+    pub fn run_solution_quietly(day: Day, is_release: bool) -> Result<Vec<String>, Error> {
+        let day_padded = day.to_string();
+        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+
+        if is_release {
+            args.push("--release");
+        }
+
+        let output = Command::new("cargo")
+            .args(&args)
+            .stderr(Stdio::null())
+            .output()?;
+
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(String::from)
+            .collect())
+    }
+
     pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
         let mut timings = super::Timing {
             day,
             part_1: None,
             part_2: None,
             total_nanos: 0_f64,
+            phases: parse_phases(output),
+            resources: output.iter().find_map(|l| parse_resources(l)),
         };
 
         output
@@ -164,6 +254,68 @@ pub mod child_commands {
         timings
     }
 
+    /// Parse the phase breakdowns printed below each part, e.g. `  ↳ parse (1.2ms)`.
+    fn parse_phases(output: &[String]) -> Vec<super::PhaseTiming> {
+        let mut part = 0;
+        let mut phases = vec![];
+
+        for line in output {
+            // part lines start with the intermediate output that `\r` moved the cursor over.
+            if line.contains("Part 1:") {
+                part = 1;
+            } else if line.contains("Part 2:") {
+                part = 2;
+            }
+
+            let content = line.trim_start_matches(' ');
+            let Some(phase) = content.strip_prefix("↳ ") else {
+                continue;
+            };
+
+            let Some((name, nanos)) = phase
+                .strip_suffix(')')
+                .and_then(|s| s.rsplit_once(" ("))
+                .and_then(|(name, time)| Some((name, parse_duration(time)?)))
+            else {
+                eprintln!("Could not parse phase from line: {line}");
+                continue;
+            };
+
+            phases.push(super::PhaseTiming {
+                part,
+                depth: ((line.len() - content.len()) / 2).saturating_sub(1),
+                name: name.to_string(),
+                nanos,
+            });
+        }
+
+        phases
+    }
+
+    /// Parse the resource usage printed after all parts, e.g. `Resources: 1.2ms user, 0.3ms sys, 2048 kB peak RSS`.
+    fn parse_resources(line: &str) -> Option<super::ResourceTiming> {
+        let (_, usage) = line.split_once("Resources: ")?;
+        let usage = usage.split('\x1b').next()?;
+
+        let mut resources = super::ResourceTiming {
+            user_nanos: 0_f64,
+            system_nanos: 0_f64,
+            peak_rss_kb: None,
+        };
+
+        for field in usage.split(", ") {
+            if let Some(user) = field.strip_suffix(" user") {
+                resources.user_nanos = parse_duration(user)?;
+            } else if let Some(system) = field.strip_suffix(" sys") {
+                resources.system_nanos = parse_duration(system)?;
+            } else if let Some(kb) = field.strip_suffix(" kB peak RSS") {
+                resources.peak_rss_kb = kb.parse().ok();
+            }
+        }
+
+        Some(resources)
+    }
+
     fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
         s.split(postfix).next()?.parse().ok()
     }
@@ -179,14 +331,17 @@ pub mod child_commands {
             .next()?
             .trim();
 
-        let parsed_timing = match str_timing {
+        Some((str_timing, parse_duration(str_timing)?))
+    }
+
+    /// Parse a duration formatted with `{:?}` to nanoseconds.
+    fn parse_duration(s: &str) -> Option<f64> {
+        match s {
             s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
             s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
             s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
             s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+        }
     }
 
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -239,6 +394,49 @@ pub mod child_commands {
             assert_eq!(res.part_2.unwrap(), "100ms");
         }
 
+        #[test]
+        fn parses_phases() {
+            let res = parse_exec_time(
+                &[
+                    "Part 1: 0 (1ms @ 10 samples)".into(),
+                    "  ↳ parse (200.0µs)".into(),
+                    "    ↳ tokenize (1.5µs)".into(),
+                    "Part 2: ▼ (2ms @ 10 samples)".into(),
+                    "#..#".into(),
+                    "  ↳ search (1.2ms)".into(),
+                    "".into(),
+                ],
+                day!(1),
+            );
+            let phases: Vec<(u8, usize, &str)> = res
+                .phases
+                .iter()
+                .map(|p| (p.part, p.depth, p.name.as_str()))
+                .collect();
+            assert_eq!(
+                phases,
+                [(1, 0, "parse"), (1, 1, "tokenize"), (2, 0, "search")]
+            );
+            assert_approx_eq!(res.phases[0].nanos, 200000_f64);
+            assert_approx_eq!(res.total_nanos, 3000000_f64);
+        }
+
+        #[test]
+        fn parses_resources() {
+            let res = parse_exec_time(
+                &[
+                    "Part 1: 0 (1ms @ 10 samples)".into(),
+                    "\x1b[3mResources: 1.2s user, 300.0µs sys, 2048 kB peak RSS\x1b[0m".into(),
+                ],
+                day!(1),
+            );
+            let resources = res.resources.unwrap();
+            assert_approx_eq!(resources.user_nanos, 1200000000_f64);
+            assert_approx_eq!(resources.system_nanos, 300000_f64);
+            assert_eq!(resources.peak_rss_kb, Some(2048));
+            assert_approx_eq!(res.total_nanos, 1000000_f64);
+        }
+
         #[test]
         fn parses_missing_parts() {
             let res = parse_exec_time(
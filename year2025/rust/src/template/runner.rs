@@ -1,35 +1,120 @@
 /// Encapsulates code that interacts with solution functions.
 use std::fmt::Display;
 use std::hint::black_box;
-use std::io::{Write, stdout};
-use std::process::Output;
+use std::io::{BufRead, Write, stdin, stdout};
 use std::time::{Duration, Instant};
 use std::{cmp, env, process};
 
 use crate::template::ANSI_BOLD;
-use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
+use crate::template::answers::Answers;
+use crate::template::consistency::Tally;
+use crate::template::phase::{self, PhaseRecord};
+use crate::template::remote::{Client, RequestError, SubmissionOutcome};
+use crate::template::resources;
+use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli, ocr, puzzles};
 
 pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let mut tally = Tally::default();
+    let format_answer = |result: &Option<T>| {
+        result
+            .as_ref()
+            .map_or_else(|| "✖".to_string(), ToString::to_string)
+    };
+
+    let (result, duration, samples, phases) = run_timed(
+        &func,
+        input,
+        |result| print_result(result, &part_str, ""),
+        |result| tally.add(format_answer(result)),
+    );
 
     print_result(&result, &part_str, &format_duration(&duration, samples));
+    print_phases(&phases);
+
+    tally.add(format_answer(&result));
+    for _ in tally.runs()..repeat_count() {
+        tally.add(format_answer(&func(input)));
+    }
+    if !tally.is_consistent() {
+        println!(
+            "{part_str}: {ANSI_BOLD}⚠ nondeterministic{ANSI_RESET}, {}",
+            tally.summary()
+        );
+    }
 
     if let Some(result) = result {
-        submit_result(result, day, part);
+        let answer = result.to_string();
+        submit_result(ocr_result(&answer).unwrap_or(answer), day, part);
+    }
+}
+
+/// Number of times each part should run at least to check its answers are consistent, from `--repeat N`.
+fn repeat_count() -> usize {
+    let args: Vec<String> = env::args().collect();
+    args.iter()
+        .position(|x| x == "--repeat")
+        .and_then(|index| args.get(index + 1))
+        .and_then(|count| count.parse().ok())
+        .unwrap_or(1)
+}
+
+/// Read the letters of a multiline result, if `--ocr` was passed.
+fn ocr_result(result: &str) -> Option<String> {
+    if result.contains('\n') && env::args().any(|x| x == "--ocr") {
+        ocr::recognize(result)
+    } else {
+        None
+    }
+}
+
+/// Print the CPU time and peak memory used by the whole process, e.g. after all parts of a day ran.
+pub fn print_resources() {
+    let Some(usage) = resources::current() else {
+        return;
+    };
+
+    let mut line = format!(
+        "Resources: {:.1?} user, {:.1?} sys",
+        usage.user, usage.system
+    );
+    if let Some(peak_rss_kb) = usage.peak_rss_kb {
+        line.push_str(&format!(", {peak_rss_kb} kB peak RSS"));
+    }
+    println!("{ANSI_ITALIC}{line}{ANSI_RESET}");
+}
+
+/// Record an accepted answer and pick up the part two description once part one is solved.
+fn record_correct_answer(day: Day, part: u8, answer: &str) {
+    let mut answers = Answers::read_from_file();
+    answers.record(day, part, answer);
+    if let Err(e) = answers.store_file() {
+        eprintln!("Failed to store answer: {e}");
+    }
+
+    if part == 1 && puzzles::stored_parts(day) < 2 {
+        println!("Refreshing puzzle description for part two...");
+        if let Err(e) = puzzles::refresh(day) {
+            eprintln!("Failed to refresh puzzle: {e}");
+        }
     }
 }
 
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
 ///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///
+/// The phases recorded by the solution are those of the first execution.
+/// The results of the bench iterations are passed to `observe`, e.g. to check that they don't differ.
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+    mut observe: impl FnMut(&T),
+) -> (T, Duration, u128, Vec<PhaseRecord>) {
+    phase::take();
+
     let timer = Instant::now();
     let result = {
         #[cfg(feature = "dhat-heap")]
@@ -38,19 +123,27 @@ fn run_timed<I: Copy, T>(
         func(input)
     };
     let base_time = timer.elapsed();
+    let phases = phase::take();
 
     hook(&result);
 
     let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+        let run = bench(func, input, &base_time, &mut observe);
+        phase::take();
+        run
     } else {
         (base_time, 1)
     };
 
-    (result, run.0, run.1)
+    (result, run.0, run.1, phases)
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+fn bench<I: Copy, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    observe: &mut impl FnMut(&T),
+) -> (Duration, u128) {
     let mut stdout = stdout();
 
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
@@ -63,8 +156,9 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
 
     for _ in 0..bench_iterations {
         let timer = Instant::now();
-        black_box(func(black_box(input)));
+        let result = black_box(func(black_box(input)));
         timers.push(timer.elapsed());
+        observe(&result);
     }
 
     (
@@ -103,6 +197,9 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                     print!("\r");
                     println!("{str}");
                     println!("{result}");
+                    if let Some(letters) = ocr_result(&result.to_string()) {
+                        println!("{part}: {ANSI_BOLD}{letters}{ANSI_RESET} (OCR)");
+                    }
                 }
             } else {
                 let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
@@ -125,14 +222,49 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
     }
 }
 
+/// Print the phases of a part as an indented breakdown, e.g. `  ↳ parse (1.2ms)`.
+fn print_phases(phases: &[PhaseRecord]) {
+    for record in phases {
+        let indent = "  ".repeat(record.depth + 1);
+        println!("{indent}↳ {} ({:.1?})", record.name, record.duration);
+    }
+}
+
+/// Reject answers that can't possibly be correct, before spending a submission on them.
+fn validate_answer(answer: &str) -> Result<(), &'static str> {
+    if answer.is_empty() {
+        Err("the answer is empty")
+    } else if answer == "None" {
+        Err("the answer is `None`")
+    } else if answer.contains(char::is_whitespace) {
+        Err("the answer contains whitespace or newlines")
+    } else {
+        Ok(())
+    }
+}
+
+/// Ask the user to confirm on `input`. Anything but `y` or `yes` declines.
+fn confirm(prompt: &str, input: &mut impl BufRead) -> bool {
+    print!("{prompt} [y/N] ");
+    let _ = stdout().flush();
+
+    let mut line = String::new();
+    if input.read_line(&mut line).is_err() {
+        return false;
+    }
+    matches!(line.trim().to_lowercase().as_str(), "y" | "yes")
+}
+
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
+///  2. the answer looks valid and the user confirms it.
+///
+/// The example tests are checked by the `solve` command before the solution is started.
 fn submit_result<T: Display>(
     result: T,
     day: Day,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+) -> Option<Result<SubmissionOutcome, RequestError>> {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
@@ -155,13 +287,75 @@ fn submit_result<T: Display>(
         return None;
     }
 
-    if aoc_cli::check().is_err() {
+    if cfg!(debug_assertions) {
         eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
+            "Refusing to submit from a debug build. Format: cargo solve {day} --release --submit {part}"
         );
         process::exit(1);
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    let answer = result.to_string();
+
+    if let Err(reason) = validate_answer(&answer) {
+        eprintln!("Refusing to submit: {reason}.");
+        process::exit(1);
+    }
+
+    if !confirm(
+        &format!("Submit {ANSI_BOLD}{answer}{ANSI_RESET} for day {day}, part {part}?"),
+        &mut stdin().lock(),
+    ) {
+        println!("Submission cancelled.");
+        return None;
+    }
+
+    let Some(year) = aoc_cli::get_year() else {
+        eprintln!("Refusing to submit: {}", RequestError::NoYear);
+        process::exit(1);
+    };
+
+    println!("Submitting result...");
+    let outcome = Client::from_env().submit(day, year, part, &answer);
+
+    match &outcome {
+        Ok((outcome, message)) => {
+            println!("{message}");
+            if *outcome == SubmissionOutcome::Correct {
+                record_correct_answer(day, part, &answer);
+            }
+        }
+        Err(e) => eprintln!("Failed to submit: {e}"),
+    }
+
+    Some(outcome.map(|(outcome, _)| outcome))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{confirm, validate_answer};
+
+    #[test]
+    fn accepts_valid_answers() {
+        assert!(validate_answer("42").is_ok());
+        assert!(validate_answer("-17").is_ok());
+        assert!(validate_answer("ABCDEFGH").is_ok());
+    }
+
+    #[test]
+    fn rejects_invalid_answers() {
+        assert!(validate_answer("").is_err());
+        assert!(validate_answer("None").is_err());
+        assert!(validate_answer("1 2").is_err());
+        assert!(validate_answer("#..#\n#..#").is_err());
+        assert!(validate_answer("42\n").is_err());
+    }
+
+    #[test]
+    fn confirms_only_on_yes() {
+        assert!(confirm("?", &mut "y\n".as_bytes()));
+        assert!(confirm("?", &mut "Yes\n".as_bytes()));
+        assert!(!confirm("?", &mut "\n".as_bytes()));
+        assert!(!confirm("?", &mut "n\n".as_bytes()));
+        assert!(!confirm("?", &mut "".as_bytes()));
+    }
 }
@@ -0,0 +1,160 @@
+//! Snapshot assertions for outputs that don't fit `assert_eq!` well: rendered grids, simulation frames or letters.
+//!
+//! Snapshots are stored in `data/snapshots/{day}-{name}.txt`. Run the tests with `AOC_UPDATE_SNAPSHOTS=1`
+//! (or `cargo snapshots --update-snapshots`) to write the current outputs as the new snapshots.
+//! Trailing newlines are ignored, so snapshots edited by hand may end with one or not.
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+use crate::template::Day;
+
+/// Environment variable that switches snapshot assertions to writing the snapshots instead.
+pub const UPDATE_ENV: &str = "AOC_UPDATE_SNAPSHOTS";
+
+fn snapshot_path(day: Day, name: &str) -> PathBuf {
+    env::current_dir()
+        .unwrap()
+        .join("data")
+        .join("snapshots")
+        .join(format!("{day}-{name}.txt"))
+}
+
+fn is_update_mode() -> bool {
+    env::var(UPDATE_ENV).is_ok_and(|value| !value.is_empty() && value != "0")
+}
+
+/// Compare `actual` with the stored snapshot `name` of `day`, panicking with a line diff on mismatch.
+pub fn assert_snapshot(day: Day, name: &str, actual: impl Display) {
+    check_snapshot(
+        &snapshot_path(day, name),
+        &actual.to_string(),
+        is_update_mode(),
+    );
+}
+
+/// Compare `actual` with the snapshot at `path`, or write it there if `update` is set.
+fn check_snapshot(path: &Path, actual: &str, update: bool) {
+    let actual = actual.trim_end_matches('\n');
+
+    if update {
+        fs::create_dir_all(path.parent().unwrap()).expect("could not create snapshot folder");
+        fs::write(path, format!("{actual}\n")).expect("could not write snapshot");
+        return;
+    }
+
+    let Ok(expected) = fs::read_to_string(path) else {
+        panic!(
+            "snapshot `{}` does not exist, run with {UPDATE_ENV}=1 to create it",
+            path.display()
+        );
+    };
+
+    let expected = expected.trim_end_matches('\n');
+    if expected != actual {
+        panic!(
+            "snapshot `{}` does not match, run with {UPDATE_ENV}=1 to update it\n{}",
+            path.display(),
+            diff(expected, actual)
+        );
+    }
+}
+
+/// Line diff of two texts: unchanged lines are prefixed with ` `, removed lines with `-` and added lines with `+`.
+pub fn diff(expected: &str, actual: &str) -> String {
+    let old: Vec<&str> = expected.lines().collect();
+    let new: Vec<&str> = actual.lines().collect();
+
+    // longest common subsequence of the lines following each position.
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            lines.push(format!(" {}", old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            lines.push(format!("-{}", old[i]));
+            i += 1;
+        } else {
+            lines.push(format!("+{}", new[j]));
+            j += 1;
+        }
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{check_snapshot, diff};
+    use std::{env, fs, path::PathBuf};
+
+    fn temp_path(name: &str) -> PathBuf {
+        env::temp_dir()
+            .join(format!("aoc-snapshot-{}", std::process::id()))
+            .join(format!("{name}.txt"))
+    }
+
+    #[test]
+    fn diffs_lines() {
+        assert_eq!(diff("a\nb\nc", "a\nb\nc"), " a\n b\n c");
+        assert_eq!(diff("a\nb\nc", "a\nx\nc"), " a\n-b\n+x\n c");
+        assert_eq!(diff("a\nb", "a\nb\nc"), " a\n b\n+c");
+        assert_eq!(diff("#..\n.#.", ".#."), "-#..\n .#.");
+    }
+
+    #[test]
+    fn writes_snapshots_in_update_mode() {
+        let path = temp_path("update");
+        let _ = fs::remove_file(&path);
+
+        check_snapshot(&path, "#.\n.#", true);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "#.\n.#\n");
+        check_snapshot(&path, "#.\n.#", false);
+
+        check_snapshot(&path, "##", true);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "##\n");
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn ignores_trailing_newlines() {
+        let path = temp_path("newlines");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "a\nb\n\n").unwrap();
+
+        check_snapshot(&path, "a\nb", false);
+        check_snapshot(&path, "a\nb\n", false);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "does not exist")]
+    fn panics_on_missing_snapshot() {
+        check_snapshot(&temp_path("missing"), "a", false);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "does not match, run with AOC_UPDATE_SNAPSHOTS=1 to update it\n a\n-b\n+x"
+    )]
+    fn panics_on_mismatch() {
+        let path = temp_path("mismatch");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "a\nb\n").unwrap();
+
+        check_snapshot(&path, "a\nx", false);
+    }
+}
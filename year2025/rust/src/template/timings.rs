@@ -12,6 +12,25 @@ pub struct Timing {
     pub part_1: Option<String>,
     pub part_2: Option<String>,
     pub total_nanos: f64,
+    pub phases: Vec<PhaseTiming>,
+    pub resources: Option<ResourceTiming>,
+}
+
+/// Represents the duration of a phase recorded by a solution part, see [`crate::template::phase`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct PhaseTiming {
+    pub part: u8,
+    pub depth: usize,
+    pub name: String,
+    pub nanos: f64,
+}
+
+/// Represents the CPU time and peak memory of a day's process, see `runner::print_resources`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResourceTiming {
+    pub user_nanos: f64,
+    pub system_nanos: f64,
+    pub peak_rss_kb: Option<u64>,
 }
 
 /// Represents benchmark times for a set of days.
@@ -51,7 +70,7 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
@@ -133,6 +152,51 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        map.insert(
+            "phases".into(),
+            JsonValue::Array(value.phases.iter().map(JsonValue::from).collect()),
+        );
+
+        map.insert(
+            "resources".into(),
+            value
+                .resources
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl From<&ResourceTiming> for JsonValue {
+    fn from(value: &ResourceTiming) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("user_nanos".into(), JsonValue::Number(value.user_nanos));
+        map.insert("system_nanos".into(), JsonValue::Number(value.system_nanos));
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "peak_rss_kb".into(),
+            value
+                .peak_rss_kb
+                .map_or(JsonValue::Null, |kb| JsonValue::Number(kb as f64)),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl From<&PhaseTiming> for JsonValue {
+    fn from(value: &PhaseTiming) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        #[allow(clippy::cast_precision_loss)]
+        map.insert("depth".into(), JsonValue::Number(value.depth as f64));
+        map.insert("name".into(), JsonValue::String(value.name.clone()));
+        map.insert("nanos".into(), JsonValue::Number(value.nanos));
+
         JsonValue::Object(map)
     }
 }
@@ -166,11 +230,79 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        // timings stored before phases were recorded don't have the key.
+        let phases = match json.get("phases") {
+            Some(v) => v
+                .get::<Vec<JsonValue>>()
+                .ok_or("Expected timing.phases to be an array.")?
+                .iter()
+                .map(PhaseTiming::try_from)
+                .collect::<Result<_, _>>()?,
+            None => vec![],
+        };
+
+        let resources = match json.get("resources") {
+            Some(v) if !v.is_null() => Some(ResourceTiming::try_from(v)?),
+            _ => None,
+        };
+
         Ok(Timing {
             day,
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
             total_nanos,
+            phases,
+            resources,
+        })
+    }
+}
+
+impl TryFrom<&JsonValue> for ResourceTiming {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected resources to be a JSON object.")?;
+
+        let number = |key: &str| json.get(key).and_then(|v| v.get::<f64>().copied());
+
+        Ok(ResourceTiming {
+            user_nanos: number("user_nanos")
+                .ok_or("Expected resources.user_nanos to be a number.")?,
+            system_nanos: number("system_nanos")
+                .ok_or("Expected resources.system_nanos to be a number.")?,
+            peak_rss_kb: number("peak_rss_kb").map(|kb| kb as u64),
+        })
+    }
+}
+
+impl TryFrom<&JsonValue> for PhaseTiming {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected phase to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected phase.{key} to be a number."))
+        };
+
+        let name = json
+            .get("name")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected phase.name to be a string.")?;
+
+        Ok(PhaseTiming {
+            part: number("part")? as u8,
+            depth: number("depth")? as usize,
+            name: name.clone(),
+            nanos: number("nanos")?,
         })
     }
 }
@@ -191,18 +323,24 @@ mod tests {
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
+                    phases: vec![],
+                    resources: None,
                 },
                 Timing {
                     day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
+                    phases: vec![],
+                    resources: None,
                 },
                 Timing {
                     day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: None,
                     total_nanos: 4e+10,
+                    phases: vec![],
+                    resources: None,
                 },
             ],
         }
@@ -223,6 +361,20 @@ mod tests {
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_json_resources() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000, "resources": { "user_nanos": 2000, "system_nanos": 1000, "peak_rss_kb": 2048 } }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let resources = timings.data[0].resources.clone().unwrap();
+            assert_eq!(resources.peak_rss_kb, Some(2048));
+
+            let json = tinyjson::JsonValue::try_from(timings)
+                .unwrap()
+                .stringify()
+                .unwrap();
+            assert!(json.contains(r#""peak_rss_kb":2048"#));
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -282,10 +434,12 @@ mod tests {
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
                     total_nanos: 3_000_000_000_f64,
+                    phases: vec![],
+                    resources: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
@@ -296,10 +450,12 @@ mod tests {
                     part_1: Some("1ms".into()),
                     part_2: None,
                     total_nanos: 1_000_000_000_f64,
+                    phases: vec![],
+                    resources: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
@@ -310,10 +466,12 @@ mod tests {
                     part_1: None,
                     part_2: None,
                     total_nanos: 0.0,
+                    phases: vec![],
+                    resources: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
     }
 
@@ -334,6 +492,8 @@ mod tests {
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
+                    phases: vec![],
+                    resources: None,
                 }],
             };
             let merged = timings.merge(&other);
@@ -354,6 +514,8 @@ mod tests {
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
+                    phases: vec![],
+                    resources: None,
                 }],
             };
             let merged = timings.merge(&other);
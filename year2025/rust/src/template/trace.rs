@@ -0,0 +1,152 @@
+//! Leveled debug output for solutions, compiled out of release builds.
+//!
+//! ```ignore
+//! advent_of_code::aoc_debug!("cycle {clock}: x={x}");
+//! ```
+//!
+//! Messages are printed to stderr when enabled by `AOC_LOG`, e.g. `AOC_LOG=debug cargo solve 01`,
+//! or by `--verbose`, e.g. `cargo solve 01 --verbose`.
+use std::sync::OnceLock;
+use std::{env, fmt};
+
+/// Verbosity of a trace message, from least to most verbose.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Off,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    fn parse(s: &str) -> Option<Level> {
+        match s.trim().to_lowercase().as_str() {
+            "off" => Some(Level::Off),
+            "debug" => Some(Level::Debug),
+            "trace" => Some(Level::Trace),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Level::Off => write!(f, "OFF"),
+            Level::Debug => write!(f, "DEBUG"),
+            Level::Trace => write!(f, "TRACE"),
+        }
+    }
+}
+
+/// Which messages to print, parsed from `AOC_LOG`, e.g. `debug`, `02=trace` or `debug,01=off`.
+/// A directive without a target sets the default level, `--verbose` raises the default to `debug`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Filter {
+    default: Level,
+    targets: Vec<(String, Level)>,
+}
+
+impl Filter {
+    pub fn parse(spec: &str, verbose: bool) -> Filter {
+        let mut default = if verbose { Level::Debug } else { Level::Off };
+        let mut targets = Vec::new();
+
+        for directive in spec.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                Some((target, level)) => {
+                    if let Some(level) = Level::parse(level) {
+                        targets.push((target.trim().to_string(), level));
+                    }
+                }
+                None => {
+                    if let Some(level) = Level::parse(directive) {
+                        default = default.max(level);
+                    }
+                }
+            }
+        }
+
+        Filter { default, targets }
+    }
+
+    /// Whether a message of `level` logged from `module_path` passes the filter.
+    /// Targets match any segment of the module path, e.g. `02` matches the `02` solution binary.
+    pub fn enabled(&self, level: Level, module_path: &str) -> bool {
+        let max_level = self
+            .targets
+            .iter()
+            .rev()
+            .find(|(target, _)| module_path.split("::").any(|segment| segment == target))
+            .map_or(self.default, |(_, level)| *level);
+        level != Level::Off && level <= max_level
+    }
+}
+
+fn filter() -> &'static Filter {
+    static FILTER: OnceLock<Filter> = OnceLock::new();
+    FILTER.get_or_init(|| {
+        let spec = env::var("AOC_LOG").unwrap_or_default();
+        let verbose = env::args().any(|arg| arg == "--verbose");
+        Filter::parse(&spec, verbose)
+    })
+}
+
+pub fn enabled(level: Level, module_path: &str) -> bool {
+    filter().enabled(level, module_path)
+}
+
+/// Print a message to stderr if `level` is enabled for the calling module. Compiled out of release builds.
+#[macro_export]
+macro_rules! aoc_log {
+    ($level:expr, $($arg:tt)+) => {
+        if cfg!(debug_assertions) && $crate::template::trace::enabled($level, module_path!()) {
+            eprintln!("[{} {}] {}", $level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+/// Print a debug message, see [`aoc_log`].
+#[macro_export]
+macro_rules! aoc_debug {
+    ($($arg:tt)+) => {
+        $crate::aoc_log!($crate::template::trace::Level::Debug, $($arg)+)
+    };
+}
+
+/// Print a trace message, see [`aoc_log`].
+#[macro_export]
+macro_rules! aoc_trace {
+    ($($arg:tt)+) => {
+        $crate::aoc_log!($crate::template::trace::Level::Trace, $($arg)+)
+    };
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Filter, Level};
+
+    #[test]
+    fn filters_by_level_and_target() {
+        let filter = Filter::parse("debug,02=trace,03=off", false);
+        assert!(filter.enabled(Level::Debug, "01"));
+        assert!(!filter.enabled(Level::Trace, "01"));
+        assert!(filter.enabled(Level::Trace, "02"));
+        assert!(filter.enabled(Level::Trace, "02::grid"));
+        assert!(!filter.enabled(Level::Debug, "03"));
+    }
+
+    #[test]
+    fn is_off_by_default() {
+        assert!(!Filter::parse("", false).enabled(Level::Debug, "01"));
+        assert!(!Filter::parse("debug", false).enabled(Level::Trace, "01"));
+        assert!(Filter::parse("trace", false).enabled(Level::Trace, "01"));
+    }
+
+    #[test]
+    fn verbose_enables_debug() {
+        assert!(Filter::parse("", true).enabled(Level::Debug, "01"));
+        assert!(!Filter::parse("", true).enabled(Level::Trace, "01"));
+        assert!(!Filter::parse("01=off", true).enabled(Level::Debug, "01"));
+        assert!(Filter::parse("trace", true).enabled(Level::Trace, "01"));
+    }
+}